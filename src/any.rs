@@ -0,0 +1,411 @@
+use {
+    crate::{
+        complement::Complement, difference::Difference, intersection::Intersection, ops::*,
+        symmetric_difference::SymmetricDifference, union::Union, Bits1024, Bits1048576, Bits128,
+        Bits131072, Bits16384, Bits16777216, Bits2048, Bits2097152, Bits256, Bits262144, Bits32768,
+        Bits33554432, Bits4096, Bits4194304, Bits512, Bits524288, Bits64, Bits65536, Bits67108864,
+        Bits8192, Bits8388608,
+    },
+    alloc::boxed::Box,
+};
+
+/// Bit-set with layout selected at runtime.
+///
+/// Wraps one of bit-set aliases from `Bits64` to `Bits67108864`.
+/// Layouts larger than `Bits128` are boxed to keep the enum small.
+///
+/// Grows to the next sufficient layout in place when bit with larger index is set.
+/// Never shrinks on its own.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut set = AnyBitSet::with_capacity(100);
+/// assert_eq!(set.capacity(), 128);
+///
+/// set.set(5);
+/// set.set(100_000);
+/// assert_eq!(set.capacity(), 131072);
+/// assert!(set.iter_set().eq([5, 100_000]));
+/// ```
+#[derive(Clone, Debug)]
+pub enum AnyBitSet {
+    Bits64(Bits64),
+    Bits128(Bits128),
+    Bits256(Box<Bits256>),
+    Bits512(Box<Bits512>),
+    Bits1024(Box<Bits1024>),
+    Bits2048(Box<Bits2048>),
+    Bits4096(Box<Bits4096>),
+    Bits8192(Box<Bits8192>),
+    Bits16384(Box<Bits16384>),
+    Bits32768(Box<Bits32768>),
+    Bits65536(Box<Bits65536>),
+    Bits131072(Box<Bits131072>),
+    Bits262144(Box<Bits262144>),
+    Bits524288(Box<Bits524288>),
+    Bits1048576(Box<Bits1048576>),
+    Bits2097152(Box<Bits2097152>),
+    Bits4194304(Box<Bits4194304>),
+    Bits8388608(Box<Bits8388608>),
+    Bits16777216(Box<Bits16777216>),
+    Bits33554432(Box<Bits33554432>),
+    Bits67108864(Box<Bits67108864>),
+}
+
+/// Evaluates expression with bit-set wrapped in any variant.
+macro_rules! dispatch {
+    ($any:expr, $set:ident => $e:expr) => {
+        match $any {
+            AnyBitSet::Bits64($set) => $e,
+            AnyBitSet::Bits128($set) => $e,
+            AnyBitSet::Bits256($set) => $e,
+            AnyBitSet::Bits512($set) => $e,
+            AnyBitSet::Bits1024($set) => $e,
+            AnyBitSet::Bits2048($set) => $e,
+            AnyBitSet::Bits4096($set) => $e,
+            AnyBitSet::Bits8192($set) => $e,
+            AnyBitSet::Bits16384($set) => $e,
+            AnyBitSet::Bits32768($set) => $e,
+            AnyBitSet::Bits65536($set) => $e,
+            AnyBitSet::Bits131072($set) => $e,
+            AnyBitSet::Bits262144($set) => $e,
+            AnyBitSet::Bits524288($set) => $e,
+            AnyBitSet::Bits1048576($set) => $e,
+            AnyBitSet::Bits2097152($set) => $e,
+            AnyBitSet::Bits4194304($set) => $e,
+            AnyBitSet::Bits8388608($set) => $e,
+            AnyBitSet::Bits16777216($set) => $e,
+            AnyBitSet::Bits33554432($set) => $e,
+            AnyBitSet::Bits67108864($set) => $e,
+        }
+    };
+}
+
+/// Evaluates expression with two bit-sets if they have same layout
+/// or evaluates fallback expression otherwise.
+macro_rules! dispatch_same {
+    ($lhs:expr, $rhs:expr, $l:ident, $r:ident => $e:expr, _ => $fallback:expr) => {
+        match ($lhs, $rhs) {
+            (AnyBitSet::Bits64($l), AnyBitSet::Bits64($r)) => $e,
+            (AnyBitSet::Bits128($l), AnyBitSet::Bits128($r)) => $e,
+            (AnyBitSet::Bits256($l), AnyBitSet::Bits256($r)) => $e,
+            (AnyBitSet::Bits512($l), AnyBitSet::Bits512($r)) => $e,
+            (AnyBitSet::Bits1024($l), AnyBitSet::Bits1024($r)) => $e,
+            (AnyBitSet::Bits2048($l), AnyBitSet::Bits2048($r)) => $e,
+            (AnyBitSet::Bits4096($l), AnyBitSet::Bits4096($r)) => $e,
+            (AnyBitSet::Bits8192($l), AnyBitSet::Bits8192($r)) => $e,
+            (AnyBitSet::Bits16384($l), AnyBitSet::Bits16384($r)) => $e,
+            (AnyBitSet::Bits32768($l), AnyBitSet::Bits32768($r)) => $e,
+            (AnyBitSet::Bits65536($l), AnyBitSet::Bits65536($r)) => $e,
+            (AnyBitSet::Bits131072($l), AnyBitSet::Bits131072($r)) => $e,
+            (AnyBitSet::Bits262144($l), AnyBitSet::Bits262144($r)) => $e,
+            (AnyBitSet::Bits524288($l), AnyBitSet::Bits524288($r)) => $e,
+            (AnyBitSet::Bits1048576($l), AnyBitSet::Bits1048576($r)) => $e,
+            (AnyBitSet::Bits2097152($l), AnyBitSet::Bits2097152($r)) => $e,
+            (AnyBitSet::Bits4194304($l), AnyBitSet::Bits4194304($r)) => $e,
+            (AnyBitSet::Bits8388608($l), AnyBitSet::Bits8388608($r)) => $e,
+            (AnyBitSet::Bits16777216($l), AnyBitSet::Bits16777216($r)) => $e,
+            (AnyBitSet::Bits33554432($l), AnyBitSet::Bits33554432($r)) => $e,
+            (AnyBitSet::Bits67108864($l), AnyBitSet::Bits67108864($r)) => $e,
+            _ => $fallback,
+        }
+    };
+}
+
+impl AnyBitSet {
+    /// Returns empty bit-set with smallest layout that can hold `capacity` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` exceeds capacity of `Bits67108864`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::for_index(capacity.saturating_sub(1)).expect("Bit-set capacity exceeded")
+    }
+
+    /// Returns number of bits current layout can hold.
+    pub fn capacity(&self) -> usize {
+        self.max_index() + 1
+    }
+
+    /// Grows layout in place to hold at least `capacity` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` exceeds capacity of `Bits67108864`.
+    pub fn reserve(&mut self, capacity: usize) {
+        if capacity > self.capacity() {
+            self.grow(capacity - 1);
+        }
+    }
+
+    /// Returns empty bit-set with smallest layout that can hold bit with index `idx`.
+    fn for_index(idx: usize) -> Option<Self> {
+        macro_rules! pick {
+            ($($variant:ident => $e:expr),* $(,)?) => {
+                $(
+                    if idx <= <$variant as BitSetLimit>::MAX_SET_INDEX {
+                        return Some(AnyBitSet::$variant($e));
+                    }
+                )*
+            };
+        }
+
+        pick! {
+            Bits64 => 0,
+            Bits128 => 0,
+            Bits256 => Box::new(Bits256::empty()),
+            Bits512 => Box::new(Bits512::empty()),
+            Bits1024 => Box::new(Bits1024::empty()),
+            Bits2048 => Box::new(Bits2048::empty()),
+            Bits4096 => Box::new(Bits4096::empty()),
+            Bits8192 => Box::new(Bits8192::empty()),
+            Bits16384 => Box::new(Bits16384::empty()),
+            Bits32768 => Box::new(Bits32768::empty()),
+            Bits65536 => Box::new(Bits65536::empty()),
+            Bits131072 => Box::new(Bits131072::empty()),
+            Bits262144 => Box::new(Bits262144::empty()),
+            Bits524288 => Box::new(Bits524288::empty()),
+            Bits1048576 => Box::new(Bits1048576::empty()),
+            Bits2097152 => Box::new(Bits2097152::empty()),
+            Bits4194304 => Box::new(Bits4194304::empty()),
+            Bits8388608 => Box::new(Bits8388608::empty()),
+            Bits16777216 => Box::new(Bits16777216::empty()),
+            Bits33554432 => Box::new(Bits33554432::empty()),
+            Bits67108864 => Box::new(Bits67108864::empty()),
+        }
+
+        None
+    }
+
+    /// Returns maximum index current layout can hold.
+    fn max_index(&self) -> usize {
+        fn max_index_of<T: BitSetLimit>(_: &T) -> usize {
+            T::MAX_SET_INDEX
+        }
+
+        dispatch!(self, set => max_index_of(set))
+    }
+
+    /// Moves bits into smallest layout that can hold bit with index `idx`.
+    fn grow(&mut self, idx: usize) {
+        let mut grown = Self::for_index(idx).expect("Bit-set capacity exceeded");
+        crate::union_assign_by_search(&mut grown, &*self);
+        *self = grown;
+    }
+}
+
+impl BitEmpty for AnyBitSet {
+    fn empty() -> Self {
+        AnyBitSet::Bits64(0)
+    }
+}
+
+impl BitFull for AnyBitSet {
+    fn full() -> Self {
+        AnyBitSet::Bits67108864(Box::new(Bits67108864::full()))
+    }
+}
+
+impl BitTest for AnyBitSet {
+    fn test(&self, idx: usize) -> bool {
+        dispatch!(self, set => set.test(idx))
+    }
+}
+
+impl BitTestNone for AnyBitSet {
+    fn test_none(&self) -> bool {
+        dispatch!(self, set => set.test_none())
+    }
+}
+
+impl BitTestAll for AnyBitSet {
+    fn test_all(&self) -> bool {
+        match self {
+            AnyBitSet::Bits67108864(set) => set.test_all(),
+            _ => false,
+        }
+    }
+}
+
+impl BitSetLimit for AnyBitSet {
+    const MAX_SET_INDEX: usize = Bits67108864::MAX_SET_INDEX;
+}
+
+impl BitSet for AnyBitSet {
+    unsafe fn set_unchecked(&mut self, idx: usize) {
+        if idx > self.max_index() {
+            self.grow(idx);
+        }
+        dispatch!(self, set => set.set_unchecked(idx))
+    }
+}
+
+impl BitUnsetLimit for AnyBitSet {
+    const MAX_UNSET_INDEX: usize = usize::MAX;
+}
+
+impl BitUnset for AnyBitSet {
+    unsafe fn unset_unchecked(&mut self, idx: usize) {
+        if idx <= self.max_index() {
+            dispatch!(self, set => set.unset_unchecked(idx))
+        }
+    }
+}
+
+impl BitSetRange for AnyBitSet {
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        if last > self.max_index() {
+            self.grow(last);
+        }
+        dispatch!(self, set => set.set_range_unchecked(first, last))
+    }
+}
+
+impl BitUnsetRange for AnyBitSet {
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        let max = self.max_index();
+        if first <= max {
+            dispatch!(self, set => set.unset_range_unchecked(first, last.min(max)))
+        }
+    }
+}
+
+impl BitTestRange for AnyBitSet {
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        dispatch!(self, set => set.test_range_all(range))
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        dispatch!(self, set => set.test_range_any(range))
+    }
+}
+
+impl BitSearch for AnyBitSet {
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        dispatch!(self, set => set.find_first_set(lower_bound))
+    }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        dispatch!(self, set => set.word64(idx))
+    }
+}
+
+impl BitSearchRev for AnyBitSet {
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        dispatch!(self, set => set.find_last_set(upper_bound))
+    }
+}
+
+impl BitSearchUnset for AnyBitSet {
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        dispatch!(self, set => set.find_first_unset(lower_bound))
+    }
+}
+
+impl BitSearchRun for AnyBitSet {}
+
+impl BitCount for AnyBitSet {
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        dispatch!(self, set => set.count_in_range(range))
+    }
+}
+
+impl BitRankSelect for AnyBitSet {
+    fn select(&self, n: usize) -> Option<usize> {
+        dispatch!(self, set => set.select(n))
+    }
+}
+
+impl BitComplement for AnyBitSet {
+    type Output = Complement<Self>;
+
+    fn complement(self) -> Complement<Self> {
+        Complement(self)
+    }
+}
+
+impl<U> BitUnion<U> for AnyBitSet {
+    type Output = Union<Self, U>;
+
+    fn union(self, rhs: U) -> Union<Self, U> {
+        Union(self, rhs)
+    }
+}
+
+impl<U> BitIntersection<U> for AnyBitSet {
+    type Output = Intersection<Self, U>;
+
+    fn intersection(self, rhs: U) -> Intersection<Self, U> {
+        Intersection(self, rhs)
+    }
+}
+
+impl<U> BitDifference<U> for AnyBitSet {
+    type Output = Difference<Self, U>;
+
+    fn difference(self, rhs: U) -> Difference<Self, U> {
+        Difference(self, rhs)
+    }
+}
+
+impl<U> BitSymmetricDifference<U> for AnyBitSet {
+    type Output = SymmetricDifference<Self, U>;
+
+    fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl_bit_operators!(impl[] for AnyBitSet);
+impl_bit_operators!(impl['a] for &'a AnyBitSet);
+
+impl BitUnionAssign for AnyBitSet {
+    fn union_assign(&mut self, rhs: &Self) {
+        self.reserve(rhs.capacity());
+        dispatch_same!(&mut *self, rhs, l, r => l.union_assign(r), _ => crate::union_assign_by_search(self, rhs))
+    }
+}
+
+impl BitIntersectionAssign for AnyBitSet {
+    fn intersect_assign(&mut self, rhs: &Self) {
+        dispatch_same!(&mut *self, rhs, l, r => l.intersect_assign(r), _ => crate::intersect_assign_by_search(self, rhs))
+    }
+}
+
+impl BitDifferenceAssign for AnyBitSet {
+    fn difference_assign(&mut self, rhs: &Self) {
+        dispatch_same!(&mut *self, rhs, l, r => l.difference_assign(r), _ => crate::difference_assign_by_search(self, rhs))
+    }
+}
+
+impl BitSymmetricDifferenceAssign for AnyBitSet {
+    fn symmetric_difference_assign(&mut self, rhs: &Self) {
+        self.reserve(rhs.capacity());
+        dispatch_same!(&mut *self, rhs, l, r => l.symmetric_difference_assign(r), _ => crate::symmetric_difference_assign_by_search(self, rhs))
+    }
+}
+
+impl_assign_by_search!(impl[] for AnyBitSet; Union Intersection Difference SymmetricDifference);
+
+impl BitSubset for AnyBitSet {
+    fn is_subset_of(&self, rhs: &Self) -> bool {
+        dispatch_same!(self, rhs, l, r => l.is_subset_of(r), _ => Difference(self, rhs).find_first_set(0).is_none())
+    }
+}
+
+impl BitDisjoint for AnyBitSet {
+    fn is_disjoint(&self, rhs: &Self) -> bool {
+        dispatch_same!(self, rhs, l, r => l.is_disjoint(r), _ => Intersection(self, rhs).find_first_set(0).is_none())
+    }
+}
@@ -0,0 +1,575 @@
+use crate::{
+    complement::Complement, difference::Difference, intersection::Intersection, ops::*,
+    symmetric_difference::SymmetricDifference, union::Union, Layered,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+/// Number of bits each element of an array holds.
+/// Elements that are not bounded, e.g. `DynBits`, share whole index space equally.
+const fn stride<E: BitSetLimit, const N: usize>() -> usize {
+    if E::MAX_SET_INDEX == usize::MAX {
+        usize::MAX / crate::max(N, 1)
+    } else {
+        E::MAX_SET_INDEX + 1
+    }
+}
+
+/// Implements bit-set traits for arrays of bit-sets.
+///
+/// Element `i` holds bits from `i * stride` to `i * stride + E::MAX_SET_INDEX`,
+/// where `stride` is `E::MAX_SET_INDEX + 1`, same as bottom layer of `Layered`.
+/// Elements that are not bounded, e.g. `DynBits`, hold `usize::MAX / N` bits each.
+///
+/// Arrays of primitive blocks have their own implementations that operate on blocks directly.
+/// Blanket implementation for arrays of any bit-set would overlap with them,
+/// so the macro is instantiated for each kind of element instead.
+/// Arrays of other bit-sets can be supported by adding them to the list at the end of this file.
+///
+/// Binary operations are lazy, in-place operations are applied element-wise.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut array = <[Bits4096; 4]>::empty();
+/// array.set(5);
+/// array.set_range(4000..9000);
+/// assert_eq!(<[Bits4096; 4]>::MAX_SET_INDEX, 16383);
+/// assert_eq!(array.count_ones(), 5001);
+/// assert_eq!(array.find_first_set(6), Some(4000));
+/// assert_eq!(array.find_first_unset(4000), Some(9000));
+///
+/// let mut nested = [[0u64; 2]; 3];
+/// nested.set(200);
+/// nested.union_assign(&Intersection(&array, u64::MAX));
+/// assert!(nested.iter_set().eq([5, 200]));
+///
+/// let mut other = [[0u64; 2]; 3];
+/// other.set(300);
+/// nested.union_assign(&other);
+/// assert!(other.is_subset_of(&nested));
+/// assert!(nested.iter_set().eq([5, 200, 300]));
+///
+/// let mut any = <[AnyBitSet; 2]>::empty();
+/// any.set((1 << 26) + 3);
+/// assert_eq!(any[1].capacity(), 64);
+///
+/// let mut dynamic = <[DynBits; 2]>::empty();
+/// dynamic.set(usize::MAX / 2 + 70);
+/// assert_eq!(dynamic[1].find_first_set(0), Some(70));
+/// assert!(Union(&any, &dynamic)
+///     .iter_set()
+///     .eq([(1 << 26) + 3, usize::MAX / 2 + 70]));
+/// ```
+macro_rules! impl_for_array {
+    (impl[$($generics:tt)*] for $elem:ty) => {
+        impl<$($generics)*> BitFull for [$elem; N]
+        where
+            $elem: BitFull,
+        {
+            fn full() -> Self {
+                crate::make_array(<$elem>::full)
+            }
+        }
+
+        impl_for_array!(impl[$($generics)*] for $elem; unbounded);
+    };
+
+    // Elements that are not bounded cannot be full.
+    (impl[$($generics:tt)*] for $elem:ty; unbounded) => {
+        impl<$($generics)*> BitEmpty for [$elem; N]
+        where
+            $elem: BitEmpty,
+        {
+            fn empty() -> Self {
+                crate::make_array(<$elem>::empty)
+            }
+        }
+
+        impl<$($generics)*> BitTest for [$elem; N]
+        where
+            $elem: BitTest + BitSetLimit,
+        {
+            #[inline]
+            fn test(&self, idx: usize) -> bool {
+                match self.get(idx / stride::<$elem, N>()) {
+                    Some(e) => e.test(idx % stride::<$elem, N>()),
+                    None => false,
+                }
+            }
+        }
+
+        impl<$($generics)*> BitTestAll for [$elem; N]
+        where
+            $elem: BitTestAll,
+        {
+            #[inline]
+            fn test_all(&self) -> bool {
+                self.iter().all(|e| e.test_all())
+            }
+        }
+
+        impl<$($generics)*> BitTestNone for [$elem; N]
+        where
+            $elem: BitTestNone,
+        {
+            #[inline]
+            fn test_none(&self) -> bool {
+                self.iter().all(|e| e.test_none())
+            }
+        }
+
+        impl<$($generics)*> BitSetLimit for [$elem; N]
+        where
+            $elem: BitSetLimit,
+        {
+            const MAX_SET_INDEX: usize = (N - 1) * stride::<$elem, N>()
+                + crate::min(<$elem>::MAX_SET_INDEX, stride::<$elem, N>() - 1);
+        }
+
+        impl<$($generics)*> BitSet for [$elem; N]
+        where
+            $elem: BitSet,
+        {
+            #[inline]
+            unsafe fn set_unchecked(&mut self, idx: usize) {
+                debug_assert!(idx <= Self::MAX_SET_INDEX);
+                self[idx / stride::<$elem, N>()].set_unchecked(idx % stride::<$elem, N>())
+            }
+        }
+
+        impl<$($generics)*> BitUnsetLimit for [$elem; N] {
+            const MAX_UNSET_INDEX: usize = usize::MAX;
+        }
+
+        impl<$($generics)*> BitUnset for [$elem; N]
+        where
+            $elem: BitUnset + BitSetLimit,
+        {
+            #[inline]
+            unsafe fn unset_unchecked(&mut self, idx: usize) {
+                if <$elem>::MAX_UNSET_INDEX < <$elem>::MAX_SET_INDEX {
+                    panic!("This kind of array bitset cannot support bit unsetting");
+                }
+
+                if let Some(e) = self.get_mut(idx / stride::<$elem, N>()) {
+                    e.unset_unchecked(idx % stride::<$elem, N>());
+                }
+            }
+        }
+
+        impl<$($generics)*> BitSetRange for [$elem; N]
+        where
+            $elem: BitSetRange,
+        {
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                debug_assert!(first <= last && last <= Self::MAX_SET_INDEX);
+                let s = stride::<$elem, N>();
+                let (i_first, i_last) = (first / s, last / s);
+
+                for i in i_first..=i_last {
+                    let lower = if i == i_first { first % s } else { 0 };
+                    let upper = if i == i_last { last % s } else { s - 1 };
+                    self[i].set_range_unchecked(lower, upper);
+                }
+            }
+        }
+
+        impl<$($generics)*> BitUnsetRange for [$elem; N]
+        where
+            $elem: BitUnsetRange + BitSetLimit,
+        {
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                if <$elem>::MAX_UNSET_INDEX < <$elem>::MAX_SET_INDEX {
+                    panic!("This kind of array bitset cannot support bit unsetting");
+                }
+
+                if first <= Self::MAX_SET_INDEX {
+                    let s = stride::<$elem, N>();
+                    let last = last.min(Self::MAX_SET_INDEX);
+                    let (i_first, i_last) = (first / s, last / s);
+
+                    for i in i_first..=i_last {
+                        let lower = if i == i_first { first % s } else { 0 };
+                        let upper = if i == i_last { last % s } else { s - 1 };
+                        self[i].unset_range_unchecked(lower, upper);
+                    }
+                }
+            }
+        }
+
+        impl<$($generics)*> BitTestRange for [$elem; N]
+        where
+            $elem: BitTestRange + BitSetLimit,
+        {
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::range_bounds(&range, usize::MAX) {
+                    None => true,
+                    Some((_, last)) if last > Self::MAX_SET_INDEX => false,
+                    Some((first, last)) => {
+                        let s = stride::<$elem, N>();
+                        let (i_first, i_last) = (first / s, last / s);
+
+                        (i_first..=i_last).all(|i| {
+                            let lower = if i == i_first { first % s } else { 0 };
+                            let upper = if i == i_last { last % s } else { s - 1 };
+                            self[i].test_range_all(lower..=upper)
+                        })
+                    }
+                }
+            }
+
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+                    None => false,
+                    Some((first, last)) => {
+                        let s = stride::<$elem, N>();
+                        let (i_first, i_last) = (first / s, last / s);
+
+                        (i_first..=i_last).any(|i| {
+                            let lower = if i == i_first { first % s } else { 0 };
+                            let upper = if i == i_last { last % s } else { s - 1 };
+                            self[i].test_range_any(lower..=upper)
+                        })
+                    }
+                }
+            }
+        }
+
+        impl<$($generics)*> BitSearch for [$elem; N]
+        where
+            $elem: BitSearch + BitSetLimit,
+        {
+            fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+                let s = stride::<$elem, N>();
+                let mut i = lower_bound / s;
+                let mut j = lower_bound % s;
+
+                while let Some(e) = self.get(i) {
+                    if let Some(idx) = e.find_first_set(j) {
+                        return Some(i * s + idx);
+                    }
+                    i += 1;
+                    j = 0;
+                }
+
+                None
+            }
+
+            fn word64(&self, idx: usize) -> Option<u64> {
+                // Words are taken from elements, so they must not straddle them.
+                let s = stride::<$elem, N>();
+                if s % 64 != 0 {
+                    return None;
+                }
+
+                match self.get(idx / (s / 64)) {
+                    None => Some(0),
+                    Some(e) => e.word64(idx % (s / 64)),
+                }
+            }
+        }
+
+        impl<$($generics)*> BitSearchUnset for [$elem; N]
+        where
+            $elem: BitSearchUnset + BitSetLimit,
+        {
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                if lower_bound > Self::MAX_SET_INDEX {
+                    return Some(lower_bound);
+                }
+
+                let s = stride::<$elem, N>();
+                let mut i = lower_bound / s;
+                let mut j = lower_bound % s;
+
+                while let Some(e) = self.get(i) {
+                    if let Some(idx) = e.find_first_unset(j) {
+                        if idx < s {
+                            return Some(i * s + idx);
+                        }
+                    }
+                    i += 1;
+                    j = 0;
+                }
+
+                Some(Self::MAX_SET_INDEX + 1)
+            }
+        }
+
+        impl<$($generics)*> BitSearchRun for [$elem; N]
+        where
+            Self: BitSearch + BitSearchUnset + BitSetLimit,
+        {
+        }
+
+        impl<$($generics)*> BitSearchRev for [$elem; N]
+        where
+            $elem: BitSearchRev + BitSetLimit,
+        {
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                let s = stride::<$elem, N>();
+                let upper_bound = upper_bound.min(Self::MAX_SET_INDEX);
+                let mut i = upper_bound / s;
+                let mut j = upper_bound % s;
+
+                loop {
+                    if let Some(idx) = self[i].find_last_set(j) {
+                        return Some(i * s + idx);
+                    }
+                    if i == 0 {
+                        return None;
+                    }
+                    i -= 1;
+                    j = s - 1;
+                }
+            }
+        }
+
+        impl<$($generics)*> BitCount for [$elem; N]
+        where
+            $elem: BitCount + BitSetLimit,
+        {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                let (start, end) = match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+                    None => return 0,
+                    Some(bounds) => bounds,
+                };
+
+                let s = stride::<$elem, N>();
+                let (first, last) = (start / s, end / s);
+
+                (first..=last)
+                    .map(|i| {
+                        let lower = if i == first { start % s } else { 0 };
+                        let upper = if i == last { end % s } else { s - 1 };
+                        self[i].count_in_range(lower..=upper)
+                    })
+                    .sum()
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                self.iter().map(|e| e.count_ones()).sum()
+            }
+        }
+
+        impl<$($generics)*> BitRankSelect for [$elem; N]
+        where
+            $elem: BitRankSelect + BitSetLimit,
+        {
+            fn select(&self, mut n: usize) -> Option<usize> {
+                for (i, e) in self.iter().enumerate() {
+                    let count = e.count_ones();
+                    if n < count {
+                        return Some(i * stride::<$elem, N>() + e.select(n)?);
+                    }
+                    n -= count;
+                }
+                None
+            }
+        }
+
+        impl<$($generics)*> BitComplement for [$elem; N] {
+            type Output = Complement<Self>;
+
+            fn complement(self) -> Complement<Self> {
+                Complement(self)
+            }
+        }
+
+        impl<U, $($generics)*> BitUnion<U> for [$elem; N] {
+            type Output = Union<Self, U>;
+
+            fn union(self, rhs: U) -> Union<Self, U> {
+                Union(self, rhs)
+            }
+        }
+
+        impl<U, $($generics)*> BitIntersection<U> for [$elem; N] {
+            type Output = Intersection<Self, U>;
+
+            fn intersection(self, rhs: U) -> Intersection<Self, U> {
+                Intersection(self, rhs)
+            }
+        }
+
+        impl<U, $($generics)*> BitDifference<U> for [$elem; N] {
+            type Output = Difference<Self, U>;
+
+            fn difference(self, rhs: U) -> Difference<Self, U> {
+                Difference(self, rhs)
+            }
+        }
+
+        impl<U, $($generics)*> BitSymmetricDifference<U> for [$elem; N] {
+            type Output = SymmetricDifference<Self, U>;
+
+            fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+                SymmetricDifference(self, rhs)
+            }
+        }
+
+        impl<U, $($generics)*> BitUnionAssign<[U; N]> for [$elem; N]
+        where
+            $elem: BitUnionAssign<U>,
+        {
+            fn union_assign(&mut self, rhs: &[U; N]) {
+                self.iter_mut().zip(rhs).for_each(|(e, r)| e.union_assign(r));
+            }
+        }
+
+        impl<U, $($generics)*> BitIntersectionAssign<[U; N]> for [$elem; N]
+        where
+            $elem: BitIntersectionAssign<U>,
+        {
+            fn intersect_assign(&mut self, rhs: &[U; N]) {
+                self.iter_mut().zip(rhs).for_each(|(e, r)| e.intersect_assign(r));
+            }
+        }
+
+        impl<U, $($generics)*> BitDifferenceAssign<[U; N]> for [$elem; N]
+        where
+            $elem: BitDifferenceAssign<U>,
+        {
+            fn difference_assign(&mut self, rhs: &[U; N]) {
+                self.iter_mut().zip(rhs).for_each(|(e, r)| e.difference_assign(r));
+            }
+        }
+
+        impl<U, $($generics)*> BitSymmetricDifferenceAssign<[U; N]> for [$elem; N]
+        where
+            $elem: BitSymmetricDifferenceAssign<U>,
+        {
+            fn symmetric_difference_assign(&mut self, rhs: &[U; N]) {
+                self.iter_mut()
+                    .zip(rhs)
+                    .for_each(|(e, r)| e.symmetric_difference_assign(r));
+            }
+        }
+
+        impl_assign_by_search!(impl[$($generics)*] for [$elem; N]; Union Intersection Difference SymmetricDifference);
+
+        impl<X, $($generics)*> BitUnionAssign<Complement<X>> for [$elem; N]
+        where
+            Self: BitSet,
+            Complement<X>: BitSearch,
+        {
+            fn union_assign(&mut self, rhs: &Complement<X>) {
+                crate::union_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<X, $($generics)*> BitIntersectionAssign<Complement<X>> for [$elem; N]
+        where
+            Self: BitSearch + BitUnset,
+            X: BitTest,
+        {
+            fn intersect_assign(&mut self, rhs: &Complement<X>) {
+                crate::intersect_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<X, $($generics)*> BitDifferenceAssign<Complement<X>> for [$elem; N]
+        where
+            Self: BitSearch + BitUnset,
+            X: BitTest,
+        {
+            fn difference_assign(&mut self, rhs: &Complement<X>) {
+                crate::difference_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<X, $($generics)*> BitSymmetricDifferenceAssign<Complement<X>> for [$elem; N]
+        where
+            Self: BitTest + BitSet + BitUnset,
+            Complement<X>: BitSearch,
+        {
+            fn symmetric_difference_assign(&mut self, rhs: &Complement<X>) {
+                crate::symmetric_difference_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<U, $($generics)*> BitSubset<[U; N]> for [$elem; N]
+        where
+            $elem: BitSubset<U>,
+        {
+            fn is_subset_of(&self, rhs: &[U; N]) -> bool {
+                self.iter().zip(rhs).all(|(e, r)| e.is_subset_of(r))
+            }
+        }
+
+        impl<X, $($generics)*> BitSubset<Complement<X>> for [$elem; N]
+        where
+            Self: BitDisjoint<X>,
+        {
+            fn is_subset_of(&self, rhs: &Complement<X>) -> bool {
+                self.is_disjoint(&rhs.0)
+            }
+        }
+
+        impl<U, $($generics)*> BitDisjoint<[U; N]> for [$elem; N]
+        where
+            $elem: BitDisjoint<U>,
+        {
+            fn is_disjoint(&self, rhs: &[U; N]) -> bool {
+                self.iter().zip(rhs).all(|(e, r)| e.is_disjoint(r))
+            }
+        }
+
+        impl<X, $($generics)*> BitDisjoint<Complement<X>> for [$elem; N]
+        where
+            Self: BitSubset<X>,
+        {
+            fn is_disjoint(&self, rhs: &Complement<X>) -> bool {
+                self.is_subset_of(&rhs.0)
+            }
+        }
+    };
+}
+
+impl_for_array!(impl[const N: usize] for bool);
+impl_for_array!(impl[T, const M: usize, const N: usize] for [T; M]);
+impl_for_array!(impl[T, B, const M: usize, const N: usize] for Layered<T, B, M>);
+impl_for_array!(impl[T, const N: usize] for Option<T>);
+
+#[cfg(feature = "alloc")]
+impl_for_array!(impl[T, const N: usize] for Box<T>);
+
+#[cfg(feature = "alloc")]
+impl_for_array!(impl[const N: usize] for crate::DynBits; unbounded);
+
+#[cfg(feature = "alloc")]
+impl_for_array!(impl[const N: usize] for crate::AnyBitSet);
+
+#[cfg(feature = "alloc")]
+impl_for_array!(impl[T, const N: usize] for Rc<T>);
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl_for_array!(impl[T, const N: usize] for Arc<T>);
+
+#[cfg(target_has_atomic = "8")]
+impl_for_array!(impl[const N: usize] for core::sync::atomic::AtomicU8);
+
+#[cfg(target_has_atomic = "16")]
+impl_for_array!(impl[const N: usize] for core::sync::atomic::AtomicU16);
+
+#[cfg(target_has_atomic = "32")]
+impl_for_array!(impl[const N: usize] for core::sync::atomic::AtomicU32);
+
+#[cfg(target_has_atomic = "64")]
+impl_for_array!(impl[const N: usize] for core::sync::atomic::AtomicU64);
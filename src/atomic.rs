@@ -0,0 +1,363 @@
+use crate::{
+    difference::Difference, intersection::Intersection, ops::*,
+    symmetric_difference::SymmetricDifference, union::Union,
+};
+
+use core::sync::atomic::Ordering;
+
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicU8;
+
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::AtomicU16;
+
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::AtomicU32;
+
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+
+/// Implements bit-set traits for atomic integers.
+///
+/// Read-only operations load value with `Ordering::Acquire`
+/// and operate on loaded primitive.
+/// Operations that take `&mut self` modify value in place without synchronization.
+/// `BitAtomic` provides operations on shared references.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// let mut mask = AtomicU32::empty();
+/// mask.set_range(4..8);
+/// assert!(!mask.test_and_set(12, Ordering::Relaxed));
+/// assert!(mask.test_and_unset(5, Ordering::Relaxed));
+/// assert!(mask.iter_set().eq([4, 6, 7, 12]));
+/// assert_eq!(mask.find_first_unset(4), Some(5));
+///
+/// let lazy = Union(mask.snapshot(Ordering::Acquire), 1u32);
+/// assert_eq!(lazy.count_ones(), 5);
+/// ```
+macro_rules! impl_for_atomic {
+    ($atomic:ident : $ty:ty : $size:literal) => {
+        impl BitEmpty for $atomic {
+            fn empty() -> Self {
+                <$atomic>::new(<$ty>::MIN)
+            }
+        }
+
+        impl BitFull for $atomic {
+            fn full() -> Self {
+                <$atomic>::new(<$ty>::MAX)
+            }
+        }
+
+        impl BitTest for $atomic {
+            #[inline]
+            fn test(&self, idx: usize) -> bool {
+                self.load(Ordering::Acquire).test(idx)
+            }
+        }
+
+        impl BitTestAll for $atomic {
+            #[inline]
+            fn test_all(&self) -> bool {
+                self.load(Ordering::Acquire).test_all()
+            }
+        }
+
+        impl BitTestNone for $atomic {
+            #[inline]
+            fn test_none(&self) -> bool {
+                self.load(Ordering::Acquire).test_none()
+            }
+        }
+
+        impl BitSetLimit for $atomic {
+            const MAX_SET_INDEX: usize = $size - 1;
+        }
+
+        impl BitSet for $atomic {
+            #[inline]
+            unsafe fn set_unchecked(&mut self, idx: usize) {
+                self.get_mut().set_unchecked(idx)
+            }
+        }
+
+        impl BitUnsetLimit for $atomic {
+            const MAX_UNSET_INDEX: usize = usize::MAX;
+        }
+
+        impl BitUnset for $atomic {
+            #[inline]
+            unsafe fn unset_unchecked(&mut self, idx: usize) {
+                self.get_mut().unset_unchecked(idx)
+            }
+        }
+
+        impl BitSetRange for $atomic {
+            #[inline]
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                self.get_mut().set_range_unchecked(first, last)
+            }
+        }
+
+        impl BitUnsetRange for $atomic {
+            #[inline]
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                self.get_mut().unset_range_unchecked(first, last)
+            }
+        }
+
+        impl BitTestRange for $atomic {
+            #[inline]
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                self.load(Ordering::Acquire).test_range_all(range)
+            }
+
+            #[inline]
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                self.load(Ordering::Acquire).test_range_any(range)
+            }
+        }
+
+        impl BitSearch for $atomic {
+            #[inline]
+            fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+                self.load(Ordering::Acquire).find_first_set(lower_bound)
+            }
+
+            #[inline]
+            fn word64(&self, idx: usize) -> Option<u64> {
+                self.load(Ordering::Acquire).word64(idx)
+            }
+        }
+
+        impl BitSearchUnset for $atomic {
+            #[inline]
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                self.load(Ordering::Acquire).find_first_unset(lower_bound)
+            }
+        }
+
+        impl BitSearchRun for $atomic {
+            #[inline]
+            fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                self.load(Ordering::Acquire).find_first_run_set(len, lower_bound)
+            }
+
+            #[inline]
+            fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                self.load(Ordering::Acquire).find_first_run_unset(len, lower_bound)
+            }
+
+            #[inline]
+            fn find_aligned_free(&self, order: u32) -> Option<usize> {
+                self.load(Ordering::Acquire).find_aligned_free(order)
+            }
+        }
+
+        impl BitSearchRev for $atomic {
+            #[inline]
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                self.load(Ordering::Acquire).find_last_set(upper_bound)
+            }
+        }
+
+        impl BitCount for $atomic {
+            #[inline]
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                self.load(Ordering::Acquire).count_in_range(range)
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                BitCount::count_ones(&self.load(Ordering::Acquire))
+            }
+        }
+
+        impl BitRankSelect for $atomic {
+            #[inline]
+            fn select(&self, n: usize) -> Option<usize> {
+                self.load(Ordering::Acquire).select(n)
+            }
+        }
+
+        impl BitUnionAssign for $atomic {
+            #[inline]
+            fn union_assign(&mut self, rhs: &Self) {
+                *self.get_mut() |= rhs.load(Ordering::Acquire);
+            }
+        }
+
+        impl BitUnionAssign<$ty> for $atomic {
+            #[inline]
+            fn union_assign(&mut self, rhs: &$ty) {
+                *self.get_mut() |= *rhs;
+            }
+        }
+
+        impl BitIntersectionAssign for $atomic {
+            #[inline]
+            fn intersect_assign(&mut self, rhs: &Self) {
+                *self.get_mut() &= rhs.load(Ordering::Acquire);
+            }
+        }
+
+        impl BitIntersectionAssign<$ty> for $atomic {
+            #[inline]
+            fn intersect_assign(&mut self, rhs: &$ty) {
+                *self.get_mut() &= *rhs;
+            }
+        }
+
+        impl BitDifferenceAssign for $atomic {
+            #[inline]
+            fn difference_assign(&mut self, rhs: &Self) {
+                *self.get_mut() &= !rhs.load(Ordering::Acquire);
+            }
+        }
+
+        impl BitDifferenceAssign<$ty> for $atomic {
+            #[inline]
+            fn difference_assign(&mut self, rhs: &$ty) {
+                *self.get_mut() &= !*rhs;
+            }
+        }
+
+        impl BitSymmetricDifferenceAssign for $atomic {
+            #[inline]
+            fn symmetric_difference_assign(&mut self, rhs: &Self) {
+                *self.get_mut() ^= rhs.load(Ordering::Acquire);
+            }
+        }
+
+        impl BitSymmetricDifferenceAssign<$ty> for $atomic {
+            #[inline]
+            fn symmetric_difference_assign(&mut self, rhs: &$ty) {
+                *self.get_mut() ^= *rhs;
+            }
+        }
+
+        impl_assign_by_search!(impl[] for $atomic; Union Intersection Difference SymmetricDifference);
+
+        impl BitSubset for $atomic {
+            fn is_subset_of(&self, rhs: &Self) -> bool {
+                self.load(Ordering::Acquire) & !rhs.load(Ordering::Acquire) == 0
+            }
+        }
+
+        impl BitSubset<$ty> for $atomic {
+            fn is_subset_of(&self, rhs: &$ty) -> bool {
+                self.load(Ordering::Acquire) & !*rhs == 0
+            }
+        }
+
+        impl BitDisjoint for $atomic {
+            fn is_disjoint(&self, rhs: &Self) -> bool {
+                self.load(Ordering::Acquire) & rhs.load(Ordering::Acquire) == 0
+            }
+        }
+
+        impl BitDisjoint<$ty> for $atomic {
+            fn is_disjoint(&self, rhs: &$ty) -> bool {
+                self.load(Ordering::Acquire) & *rhs == 0
+            }
+        }
+
+        impl BitAtomic for $atomic {
+            type Snapshot = $ty;
+
+            #[inline]
+            fn atomic_test(&self, idx: usize, order: Ordering) -> bool {
+                self.load(order).test(idx)
+            }
+
+            #[inline]
+            fn test_and_set(&self, idx: usize, order: Ordering) -> bool {
+                assert!(idx < $size, "Idx out of bounds");
+                let bit = 1 as $ty << idx;
+                self.fetch_or(bit, order) & bit != 0
+            }
+
+            #[inline]
+            fn test_and_unset(&self, idx: usize, order: Ordering) -> bool {
+                if idx < $size {
+                    let bit = 1 as $ty << idx;
+                    self.fetch_and(!bit, order) & bit != 0
+                } else {
+                    false
+                }
+            }
+
+            #[inline]
+            fn snapshot(&self, order: Ordering) -> $ty {
+                self.load(order)
+            }
+        }
+    };
+}
+
+#[cfg(target_has_atomic = "8")]
+impl_for_atomic!(AtomicU8 : u8 : 8);
+
+#[cfg(target_has_atomic = "16")]
+impl_for_atomic!(AtomicU16 : u16 : 16);
+
+#[cfg(target_has_atomic = "32")]
+impl_for_atomic!(AtomicU32 : u32 : 32);
+
+#[cfg(target_has_atomic = "64")]
+impl_for_atomic!(AtomicU64 : u64 : 64);
+
+/// Element `i` holds bits from `i * stride` to `i * stride + E::MAX_SET_INDEX`,
+/// same as other arrays of bit-sets.
+/// Each element is accessed atomically, array as a whole is not.
+impl<E, const N: usize> BitAtomic for [E; N]
+where
+    E: BitAtomic,
+    Self: BitSetLimit,
+{
+    type Snapshot = [E::Snapshot; N];
+
+    #[inline]
+    fn atomic_test(&self, idx: usize, order: Ordering) -> bool {
+        let stride = E::MAX_SET_INDEX + 1;
+        match self.get(idx / stride) {
+            Some(e) => e.atomic_test(idx % stride, order),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn test_and_set(&self, idx: usize, order: Ordering) -> bool {
+        assert!(idx <= Self::MAX_SET_INDEX, "Idx out of bounds");
+        let stride = E::MAX_SET_INDEX + 1;
+        self[idx / stride].test_and_set(idx % stride, order)
+    }
+
+    #[inline]
+    fn test_and_unset(&self, idx: usize, order: Ordering) -> bool {
+        let stride = E::MAX_SET_INDEX + 1;
+        match self.get(idx / stride) {
+            Some(e) => e.test_and_unset(idx % stride, order),
+            None => false,
+        }
+    }
+
+    fn snapshot(&self, order: Ordering) -> [E::Snapshot; N] {
+        let mut elems = self.iter();
+        crate::make_array(|| elems.next().unwrap().snapshot(order))
+    }
+}
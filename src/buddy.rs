@@ -0,0 +1,289 @@
+use crate::{id_alloc::CapacityExceeded, ops::*};
+
+/// Buddy allocator of blocks of `2^order` units for orders `0..ORDERS`.
+///
+/// Keeps bit-set of free blocks for each order.
+/// Bit `i` of bit-set for `order` is set when block at offset `i << order` is free
+/// and not merged into larger block.
+/// All bit-sets are of the same type `S`, which must hold `2^(ORDERS - 1)` bits.
+/// Bit-set for `order` needs only `2^(ORDERS - 1 - order)` bits, but bit-set types
+/// are chosen at compile time and one type per order cannot be expressed with `[S; ORDERS]`.
+/// Bit-sets for large orders use only few first bits, so layered bit-sets
+/// with lazily allocated bottom layers, e.g. `Bits65536`, keep them small:
+/// unused bottom layers cost only their top layers and empty pointers.
+/// Another bit-set of the same type marks last unit of every allocated block,
+/// so blocks can be freed only with the order they were allocated with.
+///
+/// Allocation splits smallest free block that fits,
+/// freeing merges block with its buddy as long as buddy is free.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// // 65536 units in blocks of up to 2^16 units.
+/// let mut buddy = BuddyAllocator::<Bits65536, 17>::new();
+/// assert_eq!(buddy.free_units(), 65536);
+///
+/// let a = buddy.alloc(4).unwrap();
+/// let b = buddy.alloc(0).unwrap();
+/// let c = buddy.alloc(4).unwrap();
+/// assert_eq!((a, b, c), (0, 16, 32));
+/// assert_eq!(buddy.free_blocks(0), 1);
+/// assert_eq!(buddy.free_blocks(15), 1);
+/// assert_eq!(buddy.largest_free_order(), Some(15));
+/// assert_eq!(buddy.free_units(), 65536 - 33);
+///
+/// assert!(buddy.free(a, 4));
+/// assert!(buddy.free(b, 0));
+/// assert!(buddy.free(c, 4));
+/// assert_eq!(buddy.free_blocks(16), 1);
+/// assert!((0..16).all(|order| buddy.free_blocks(order) == 0));
+///
+/// assert_eq!(buddy.alloc(16), Ok(0));
+/// assert_eq!(buddy.alloc(0), Err(CapacityExceeded));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BuddyAllocator<S, const ORDERS: usize> {
+    free: [S; ORDERS],
+    /// Last unit of every allocated block.
+    ends: S,
+}
+
+impl<S, const ORDERS: usize> BuddyAllocator<S, ORDERS>
+where
+    S: BitEmpty + BitSet,
+{
+    /// Returns allocator with all units free.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ORDERS` is zero or `S` cannot hold `2^(ORDERS - 1)` bits.
+    pub fn new() -> Self {
+        assert!(
+            ORDERS > 0 && ORDERS - 1 < usize::BITS as usize,
+            "Unsupported number of orders"
+        );
+        assert!(
+            (1usize << (ORDERS - 1)) - 1 <= S::MAX_SET_INDEX,
+            "Bit-set capacity exceeded"
+        );
+
+        let mut free = crate::make_array(S::empty);
+        free[ORDERS - 1].set(0);
+        BuddyAllocator {
+            free,
+            ends: S::empty(),
+        }
+    }
+}
+
+impl<S, const ORDERS: usize> Default for BuddyAllocator<S, ORDERS>
+where
+    S: BitEmpty + BitSet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, const ORDERS: usize> BuddyAllocator<S, ORDERS> {
+    /// Total number of units.
+    pub const CAPACITY: usize = 1 << (ORDERS - 1);
+
+    /// Returns bit-set of free blocks of specified order.
+    /// Blocks merged into larger free blocks are not included.
+    pub fn free_set(&self, order: usize) -> &S {
+        &self.free[order]
+    }
+}
+
+impl<S, const ORDERS: usize> BuddyAllocator<S, ORDERS>
+where
+    S: BitTest + BitSearch + BitSet + BitUnset,
+{
+    /// Allocates block of `2^order` units.
+    /// Returns offset of the block in units.
+    pub fn alloc(&mut self, order: usize) -> Result<usize, CapacityExceeded> {
+        let (mut k, mut i) = (order..ORDERS)
+            .find_map(|k| Some((k, self.free[k].find_first_set(0)?)))
+            .ok_or(CapacityExceeded)?;
+
+        self.free[k].unset(i);
+        while k > order {
+            // Keep lower half and free upper one.
+            k -= 1;
+            i *= 2;
+            self.free[k].set(i + 1);
+        }
+
+        let offset = i << order;
+        self.ends.set(offset + (1 << order) - 1);
+        Ok(offset)
+    }
+
+    /// Frees block of `2^order` units at `offset` allocated with `alloc`.
+    /// Returns `false` if block was not allocated with the same `order`,
+    /// e.g. if block, any part of it or block containing it is already free.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is not aligned to `2^order` or block is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut buddy = BuddyAllocator::<Bits256, 9>::new();
+    /// assert!(!buddy.free(0, 0));
+    /// assert_eq!(buddy.free_units(), 256);
+    ///
+    /// let a = buddy.alloc(2).unwrap();
+    /// assert!(!buddy.free(a + 4, 2));
+    /// assert!(!buddy.free(a, 0));
+    /// assert!(!buddy.free(a + 2, 1));
+    /// assert!(buddy.free(a, 2));
+    /// assert!(!buddy.free(a, 2));
+    /// assert_eq!(buddy.free_units(), 256);
+    /// ```
+    pub fn free(&mut self, offset: usize, order: usize) -> bool {
+        assert!(order < ORDERS, "Idx out of bounds");
+        assert!(
+            offset & ((1 << order) - 1) == 0,
+            "Offset must be aligned to block size"
+        );
+        assert!(offset < Self::CAPACITY, "Idx out of bounds");
+
+        let end = offset + (1 << order);
+        let contains_free = (0..order)
+            .any(|k| matches!(self.free[k].find_first_set(offset >> k), Some(i) if i < end >> k));
+        if contains_free || (order..ORDERS).any(|k| self.free[k].test(offset >> k)) {
+            return false;
+        }
+
+        // Units of the block are allocated. Block must start right after free unit
+        // or other allocated block and end where allocated block ends.
+        let starts_block = offset == 0 || self.ends.test(offset - 1) || self.is_free(offset - 1);
+        if !starts_block || self.ends.find_first_set(offset) != Some(end - 1) {
+            return false;
+        }
+        self.ends.unset(end - 1);
+
+        let (mut k, mut i) = (order, offset >> order);
+        while k < ORDERS - 1 && self.free[k].test(i ^ 1) {
+            // Merge with free buddy.
+            self.free[k].unset(i ^ 1);
+            k += 1;
+            i /= 2;
+        }
+        self.free[k].set(i);
+        true
+    }
+
+    /// Returns largest order of free block.
+    pub fn largest_free_order(&self) -> Option<usize> {
+        (0..ORDERS)
+            .rev()
+            .find(|&k| self.free[k].find_first_set(0).is_some())
+    }
+
+    /// Checks if unit belongs to free block.
+    fn is_free(&self, unit: usize) -> bool {
+        (0..ORDERS).any(|k| self.free[k].test(unit >> k))
+    }
+}
+
+impl<S, const ORDERS: usize> BuddyAllocator<S, ORDERS>
+where
+    S: BitCount,
+{
+    /// Returns number of free blocks of specified order.
+    /// Blocks merged into larger free blocks are not counted.
+    pub fn free_blocks(&self, order: usize) -> usize {
+        self.free[order].count_ones()
+    }
+
+    /// Returns total number of free units.
+    pub fn free_units(&self) -> usize {
+        (0..ORDERS).map(|k| self.free_blocks(k) << k).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{test_util::Rng, Bits1024},
+    };
+
+    const ORDERS: usize = 11;
+
+    /// Checks that free units of allocator are exactly units not owned by allocations.
+    fn check_free_units(buddy: &BuddyAllocator<Bits1024, ORDERS>, owned: &[bool]) {
+        assert_eq!(buddy.free_units(), owned.iter().filter(|&&x| !x).count());
+        for (unit, &owned) in owned.iter().enumerate() {
+            assert_eq!(buddy.is_free(unit), !owned);
+        }
+    }
+
+    #[test]
+    fn wrong_order_is_rejected() {
+        let mut buddy = BuddyAllocator::<Bits1024, ORDERS>::new();
+        let a = buddy.alloc(2).unwrap();
+        let b = buddy.alloc(0).unwrap();
+
+        assert!(!buddy.free(a, 0));
+        assert!(!buddy.free(a, 1));
+        assert!(!buddy.free(a + 2, 1));
+        assert!(!buddy.free(a, 3));
+        assert!(buddy.free(a, 2));
+        assert!(buddy.free(b, 0));
+        assert_eq!(buddy.free_blocks(ORDERS - 1), 1);
+    }
+
+    #[test]
+    fn matches_model() {
+        let mut rng = Rng::new(25);
+        let mut buddy = BuddyAllocator::<Bits1024, ORDERS>::new();
+        let mut owned = [false; 1024];
+        let mut live = Vec::new();
+
+        for _ in 0..5000 {
+            if live.is_empty() || rng.below(2) == 0 {
+                // Small orders are more frequent.
+                let max_order = rng.below(ORDERS);
+                let order = rng.below(max_order + 1);
+                let size = 1 << order;
+                match buddy.alloc(order) {
+                    Ok(offset) => {
+                        assert_eq!(offset % size, 0);
+                        assert!(owned[offset..offset + size].iter().all(|&x| !x));
+                        owned[offset..offset + size].fill(true);
+                        live.push((offset, order));
+                    }
+                    Err(CapacityExceeded) => {
+                        assert!(owned.chunks(size).all(|block| block.iter().any(|&x| x)));
+                    }
+                }
+            } else {
+                let (offset, order) = live.swap_remove(rng.below(live.len()));
+                assert!(buddy.free(offset, order));
+                owned[offset..offset + (1 << order)].fill(false);
+            }
+
+            // Only live allocations with their own order can be freed.
+            let order = rng.below(ORDERS);
+            let offset = rng.below(1024) >> order << order;
+            if !live.contains(&(offset, order)) {
+                assert!(!buddy.free(offset, order));
+            }
+            check_free_units(&buddy, &owned);
+        }
+
+        for (offset, order) in live {
+            assert!(buddy.free(offset, order));
+        }
+        assert_eq!(buddy.free_blocks(ORDERS - 1), 1);
+        assert_eq!(buddy.largest_free_order(), Some(ORDERS - 1));
+    }
+}
@@ -117,12 +117,87 @@ where
     }
 }
 
-impl<T> BitSearch for Complement<Complement<T>>
+impl<T> BitSetRange for Complement<T>
 where
-    T: BitSearch,
+    T: BitUnsetRange,
+{
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        self.0.unset_range_unchecked(first, last)
+    }
+}
+
+impl<T> BitUnsetRange for Complement<T>
+where
+    T: BitSetRange,
+{
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        self.0.set_range_unchecked(first, last)
+    }
+}
+
+impl<T> BitTestRange for Complement<T>
+where
+    T: BitTestRange,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        !self.0.test_range_any(range)
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        !self.0.test_range_all(range)
+    }
+}
+
+impl<T> BitSearch for Complement<T>
+where
+    T: BitSearchUnset,
 {
     fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
-        self.inner().inner().find_first_set(lower_bound)
+        self.0.find_first_unset(lower_bound)
+    }
+}
+
+impl<T> BitSearchUnset for Complement<T>
+where
+    T: BitSearch,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        self.0.find_first_set(lower_bound)
+    }
+}
+
+impl<T> BitSearchRun for Complement<T> where Self: BitSearch + BitSearchUnset + BitSetLimit {}
+
+impl<T> BitCount for Complement<T>
+where
+    T: BitCount,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::inclusive_bounds(&range, usize::MAX) {
+            None => 0,
+            Some((start, end)) => match self.0.count_in_range(start..=end) {
+                0 => (end - start).saturating_add(1),
+                count => (end - start) - (count - 1),
+            },
+        }
+    }
+}
+
+impl<T> BitSearchRev for Complement<Complement<T>>
+where
+    T: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        self.inner().inner().find_last_set(upper_bound)
     }
 }
 
@@ -167,6 +242,17 @@ where
     }
 }
 
+impl<T, U> BitSymmetricDifference<U> for Complement<T>
+where
+    T: BitSymmetricDifference<U>,
+{
+    type Output = Complement<<T as BitSymmetricDifference<U>>::Output>;
+
+    fn symmetric_difference(self, rhs: U) -> Self::Output {
+        Complement(self.0.symmetric_difference(rhs))
+    }
+}
+
 impl<T, U> BitDisjoint<U> for Complement<T>
 where
     U: BitSubset<T>,
@@ -175,3 +261,6 @@ where
         rhs.is_subset_of(&self.0)
     }
 }
+
+impl_bit_operators!(impl[T] for Complement<T>);
+impl_bit_operators!(impl['a, T] for &'a Complement<T>);
@@ -0,0 +1,429 @@
+use {
+    crate::{ops::*, Layered},
+    alloc::boxed::Box,
+    core::{
+        marker::PhantomData,
+        ptr::null_mut,
+        sync::atomic::{AtomicPtr, Ordering},
+    },
+};
+
+/// Hierarchical bit-set that can be modified from many threads at once.
+///
+/// Layout mirrors `Layered<T, Option<Box<B>>, N>`, but top layer `T`
+/// and bottom layers `B` are atomic bit-sets, e.g. `AtomicU64` and `[AtomicU64; 64]`.
+/// Bottom layers are allocated on first `set` and installed with compare-and-swap.
+/// Thread that loses the race frees its allocation and uses installed one.
+/// Bottom layers are never freed before bit-set is dropped,
+/// so readers can access them without locks.
+///
+/// `set`, `unset`, `test` and `find_first_set` are available on shared reference
+/// through `BitAtomic::{atomic_set, atomic_unset, atomic_test}` and `BitSearch`.
+///
+/// # Top layer maintenance
+///
+/// Top layer bit is set when bottom layer may be non-empty.
+/// Set and unset access both layers with `Ordering::SeqCst`.
+///
+/// * `set` sets bit in bottom layer first and in top layer second.
+/// * `unset` unsets bit in bottom layer. If bottom layer became empty,
+///   it unsets bit in top layer and then tests bottom layer again.
+///   If bottom layer is not empty anymore, top layer bit is set back.
+///
+/// Once all concurrent operations complete, top layer bit is set for every non-empty bottom layer.
+/// It may stay set for empty bottom layer when unsets race with each other,
+/// which readers tolerate by skipping empty bottom layers.
+/// Bits being set concurrently may be missed by `find_first_set` until `set` returns.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// let slots = ConcurrentLayered::<AtomicU64, [AtomicU64; 64], 64>::empty();
+///
+/// std::thread::scope(|scope| {
+///     for worker in 0..4 {
+///         let slots = &slots;
+///         scope.spawn(move || {
+///             // Workers share bottom layers and race to allocate them.
+///             for idx in (worker..10_000).step_by(4) {
+///                 slots.atomic_set(idx, Ordering::Release);
+///             }
+///             for idx in (worker..10_000).step_by(4).skip(1) {
+///                 assert!(slots.test_and_unset(idx, Ordering::AcqRel));
+///             }
+///         });
+///     }
+/// });
+///
+/// assert!(slots.iter_set().eq(0..4));
+/// assert_eq!(slots.find_first_set(2), Some(2));
+///
+/// let snapshot = slots.snapshot(Ordering::Acquire);
+/// assert!(Union(&snapshot, 32u64).iter_set().eq([0, 1, 2, 3, 5]));
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentLayered<T, B, const N: usize> {
+    top: T,
+    bottom: [AtomicPtr<B>; N],
+    marker: PhantomData<Box<B>>,
+}
+
+impl<T, B, const N: usize> ConcurrentLayered<T, B, N> {
+    fn leaf(&self, t: usize) -> Option<&B> {
+        unsafe {
+            // # Safe
+            // Pointer is either null or points to installed bottom layer,
+            // which lives as long as `self`.
+            self.bottom[t].load(Ordering::Acquire).as_ref()
+        }
+    }
+
+    fn leaf_or_install(&self, t: usize) -> &B
+    where
+        B: BitEmpty,
+    {
+        if let Some(leaf) = self.leaf(t) {
+            return leaf;
+        }
+
+        let new = Box::into_raw(Box::new(B::empty()));
+        match self.bottom[t].compare_exchange(null_mut(), new, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => unsafe {
+                // # Safe
+                // Pointer was just installed and lives as long as `self`.
+                &*new
+            },
+            Err(installed) => unsafe {
+                // # Safe
+                // `new` was never shared and `installed` lives as long as `self`.
+                drop(Box::from_raw(new));
+                &*installed
+            },
+        }
+    }
+}
+
+impl<T, B, const N: usize> Drop for ConcurrentLayered<T, B, N> {
+    fn drop(&mut self) {
+        for ptr in &mut self.bottom {
+            let ptr = *ptr.get_mut();
+            if !ptr.is_null() {
+                unsafe {
+                    // # Safe
+                    // Installed pointers come from `Box::into_raw` and are owned by `self`.
+                    drop(Box::from_raw(ptr));
+                }
+            }
+        }
+    }
+}
+
+impl<T, B, const N: usize> BitEmpty for ConcurrentLayered<T, B, N>
+where
+    T: BitEmpty,
+{
+    fn empty() -> Self {
+        ConcurrentLayered {
+            top: T::empty(),
+            bottom: crate::make_array(|| AtomicPtr::new(null_mut())),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, B, const N: usize> BitSetLimit for ConcurrentLayered<T, B, N>
+where
+    T: BitSetLimit,
+    B: BitSetLimit,
+{
+    const MAX_SET_INDEX: usize =
+        crate::min(T::MAX_SET_INDEX, N - 1) * (B::MAX_SET_INDEX + 1) + B::MAX_SET_INDEX;
+}
+
+impl<T, B, const N: usize> BitUnsetLimit for ConcurrentLayered<T, B, N> {
+    const MAX_UNSET_INDEX: usize = usize::MAX;
+}
+
+impl<T, B, const N: usize> BitTest for ConcurrentLayered<T, B, N>
+where
+    T: BitSetLimit,
+    B: BitTest + BitSetLimit,
+{
+    fn test(&self, idx: usize) -> bool {
+        if idx > Self::MAX_SET_INDEX {
+            return false;
+        }
+
+        let t = idx / (B::MAX_SET_INDEX + 1);
+        let b = idx % (B::MAX_SET_INDEX + 1);
+        self.leaf(t).is_some_and(|leaf| leaf.test(b))
+    }
+}
+
+impl<T, B, const N: usize> BitTestNone for ConcurrentLayered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitTestNone,
+{
+    fn test_none(&self) -> bool {
+        let mut t = 0;
+
+        // Top layer may have bits set for empty bottom layers.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            if self.leaf(t_set).is_some_and(|leaf| !leaf.test_none()) {
+                return false;
+            }
+            t = t_set + 1;
+        }
+
+        true
+    }
+}
+
+impl<T, B, const N: usize> BitSearch for ConcurrentLayered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitSearch + BitSetLimit,
+{
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        if lower_bound > Self::MAX_SET_INDEX {
+            return None;
+        }
+
+        let t = lower_bound / (B::MAX_SET_INDEX + 1);
+        let b = lower_bound % (B::MAX_SET_INDEX + 1);
+
+        let mut t_next = t;
+
+        // Top layer may have bits set for empty bottom layers, skip them.
+        while let Some(t_set) = self.top.find_first_set(t_next) {
+            if t_set >= N {
+                break;
+            }
+
+            let lower = if t_set == t { b } else { 0 };
+            if let Some(b) = self.leaf(t_set).and_then(|leaf| leaf.find_first_set(lower)) {
+                return Some(t_set * (B::MAX_SET_INDEX + 1) + b);
+            }
+            t_next = t_set + 1;
+        }
+
+        None
+    }
+}
+
+impl<T, B, const N: usize> BitCount for ConcurrentLayered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitCount + BitSetLimit,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (start, end) = match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+            None => return 0,
+            Some(bounds) => bounds,
+        };
+
+        let t_start = start / (B::MAX_SET_INDEX + 1);
+        let t_end = end / (B::MAX_SET_INDEX + 1);
+
+        let mut count = 0;
+        let mut t = t_start;
+
+        // Top layer may have bits set for empty bottom layers, they count zero.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set > t_end {
+                break;
+            }
+
+            let lower = if t_set == t_start {
+                start % (B::MAX_SET_INDEX + 1)
+            } else {
+                0
+            };
+
+            let upper = if t_set == t_end {
+                end % (B::MAX_SET_INDEX + 1)
+            } else {
+                B::MAX_SET_INDEX
+            };
+
+            if let Some(leaf) = self.leaf(t_set) {
+                count += leaf.count_in_range(lower..=upper);
+            }
+            t = t_set + 1;
+        }
+
+        count
+    }
+}
+
+impl<T, B, const N: usize> BitSet for ConcurrentLayered<T, B, N>
+where
+    Self: BitAtomic,
+{
+    unsafe fn set_unchecked(&mut self, idx: usize) {
+        self.atomic_set(idx, Ordering::SeqCst)
+    }
+}
+
+impl<T, B, const N: usize> BitUnset for ConcurrentLayered<T, B, N>
+where
+    Self: BitAtomic,
+{
+    unsafe fn unset_unchecked(&mut self, idx: usize) {
+        self.atomic_unset(idx, Ordering::SeqCst)
+    }
+}
+
+/// Set and unset always use `Ordering::SeqCst` as required by top layer maintenance,
+/// which is at least as strong as any `order`.
+impl<T, B, const N: usize> BitAtomic for ConcurrentLayered<T, B, N>
+where
+    T: BitAtomic,
+    T::Snapshot: BitEmpty + BitSet,
+    B: BitAtomic + BitEmpty,
+    B::Snapshot: BitTestNone,
+{
+    type Snapshot = Layered<T::Snapshot, Option<Box<B::Snapshot>>, N>;
+
+    fn atomic_test(&self, idx: usize, order: Ordering) -> bool {
+        if idx > Self::MAX_SET_INDEX {
+            return false;
+        }
+
+        let t = idx / (B::MAX_SET_INDEX + 1);
+        let b = idx % (B::MAX_SET_INDEX + 1);
+        self.leaf(t).is_some_and(|leaf| leaf.atomic_test(b, order))
+    }
+
+    fn test_and_set(&self, idx: usize, _order: Ordering) -> bool {
+        assert!(idx <= Self::MAX_SET_INDEX, "Idx out of bounds");
+
+        let t = idx / (B::MAX_SET_INDEX + 1);
+        let b = idx % (B::MAX_SET_INDEX + 1);
+
+        let was_set = self.leaf_or_install(t).test_and_set(b, Ordering::SeqCst);
+        self.top.atomic_set(t, Ordering::SeqCst);
+        was_set
+    }
+
+    fn test_and_unset(&self, idx: usize, _order: Ordering) -> bool {
+        if idx > Self::MAX_SET_INDEX {
+            return false;
+        }
+
+        let t = idx / (B::MAX_SET_INDEX + 1);
+        let b = idx % (B::MAX_SET_INDEX + 1);
+
+        let leaf = match self.leaf(t) {
+            None => return false,
+            Some(leaf) => leaf,
+        };
+
+        let was_set = leaf.test_and_unset(b, Ordering::SeqCst);
+        if was_set && leaf.snapshot(Ordering::SeqCst).test_none() {
+            self.top.atomic_unset(t, Ordering::SeqCst);
+
+            // Concurrent `set` may have set bit in bottom layer
+            // before top layer bit was unset.
+            if !leaf.snapshot(Ordering::SeqCst).test_none() {
+                self.top.atomic_set(t, Ordering::SeqCst);
+            }
+        }
+        was_set
+    }
+
+    /// Loads bits into `Layered` bit-set, omitting empty bottom layers.
+    /// Bottom layers are loaded one by one, so snapshot is not atomic as a whole.
+    fn snapshot(&self, order: Ordering) -> Self::Snapshot {
+        let mut top = T::Snapshot::empty();
+        let mut t = 0;
+        let bottom = crate::make_array(|| {
+            let leaf = self
+                .leaf(t)
+                .map(|leaf| leaf.snapshot(order))
+                .filter(|leaf| !leaf.test_none())
+                .map(Box::new);
+
+            if leaf.is_some() {
+                top.set(t);
+            }
+            t += 1;
+            leaf
+        });
+
+        Layered::from_layers(top, bottom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_util::Rng, core::sync::atomic::AtomicU64, std::thread};
+
+    type Slots = ConcurrentLayered<AtomicU64, [AtomicU64; 64], 64>;
+
+    /// Checks that top layer bit is set for every non-empty bottom layer.
+    fn check_top_layer(slots: &Slots) {
+        for t in 0..64 {
+            if let Some(leaf) = slots.leaf(t) {
+                if !leaf.snapshot(Ordering::SeqCst).test_none() {
+                    assert!(slots.top.atomic_test(t, Ordering::SeqCst), "leaf {}", t);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stress() {
+        const WORKERS: usize = 8;
+        // Few bottom layers, so that workers race on the same ones.
+        const BITS: usize = 4 * 4096;
+
+        for round in 0..20 {
+            let slots = Slots::empty();
+            let models: Vec<Vec<bool>> = thread::scope(|scope| {
+                let handles: Vec<_> = (0..WORKERS)
+                    .map(|worker| {
+                        let slots = &slots;
+                        scope.spawn(move || {
+                            let mut rng = Rng::new((round * WORKERS + worker) as u64 + 1);
+                            let mut model = vec![false; BITS];
+                            // Every worker owns indices equal to `worker` modulo `WORKERS`.
+                            for _ in 0..20_000 {
+                                let idx = rng.below(BITS / WORKERS) * WORKERS + worker;
+                                if rng.below(3) == 0 {
+                                    slots.atomic_set(idx, Ordering::Release);
+                                    model[idx] = true;
+                                } else {
+                                    let was_set = slots.test_and_unset(idx, Ordering::AcqRel);
+                                    assert_eq!(was_set, core::mem::replace(&mut model[idx], false));
+                                }
+                                if let Some(found) = slots.find_first_set(idx) {
+                                    assert!(found >= idx);
+                                }
+                            }
+                            model
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            check_top_layer(&slots);
+            let expected = (0..BITS).filter(|&idx| models[idx % WORKERS][idx]);
+            assert!(slots.iter_set().eq(expected.clone()));
+            assert_eq!(slots.count_ones(), expected.count());
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::{ops::*, union::Union},
+    crate::{iter::SetBits, ops::*, symmetric_difference::SymmetricDifference, union::Union},
     core::fmt::{self, Display},
 };
 
@@ -109,6 +109,31 @@ where
     }
 }
 
+impl<T, U> BitTestRange for Difference<T, U>
+where
+    Difference<T, U>: BitSearch + BitSearchUnset,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::range_bounds(&range, usize::MAX) {
+            None => true,
+            Some((first, last)) => match self.find_first_unset(first) {
+                None => true,
+                Some(idx) => idx > last,
+            },
+        }
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.find_set_in_range(range).is_some()
+    }
+}
+
 impl<T, U> BitSearch for Difference<T, U>
 where
     T: BitSearch,
@@ -128,6 +153,76 @@ where
     }
 }
 
+impl<T, U> BitSearchUnset for Difference<T, U>
+where
+    T: BitSearchUnset,
+    U: BitSearch,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        let t = self.0.find_first_unset(lower_bound);
+        let u = self.1.find_first_set(lower_bound);
+
+        match (t, u) {
+            (None, None) => None,
+            (Some(t), None) => Some(t),
+            (None, Some(u)) => Some(u),
+            (Some(t), Some(u)) => Some(t.min(u)),
+        }
+    }
+}
+
+impl<T, U> BitSearchRun for Difference<T, U> where Self: BitSearch + BitSearchUnset + BitSetLimit {}
+
+impl<T, U> BitCount for Difference<T, U>
+where
+    T: BitCount + BitSearch,
+    U: BitSearch,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (start, end) = match crate::inclusive_bounds(&range, usize::MAX) {
+            None => return 0,
+            Some(bounds) => bounds,
+        };
+
+        let both = crate::count_intersection(&self.0, &self.1, start..=end);
+        self.0.count_in_range(start..=end) - both
+    }
+}
+
+impl<T, U> BitSearchRev for Difference<T, U>
+where
+    T: BitSearchRev,
+    U: BitTest,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        let mut idx = self.0.find_last_set(upper_bound)?;
+        loop {
+            if !self.1.test(idx) {
+                return Some(idx);
+            } else if idx > 0 {
+                idx = self.0.find_last_set(idx - 1)?;
+            } else {
+                return None;
+            }
+        }
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a Difference<T, U>
+where
+    Difference<T, U>: BitSearch + BitSetLimit,
+{
+    type Item = usize;
+    type IntoIter = SetBits<&'a Difference<T, U>>;
+
+    fn into_iter(self) -> SetBits<&'a Difference<T, U>> {
+        SetBits::new(self)
+    }
+}
+
 impl<T, U> BitComplement for Difference<T, U>
 where
     T: BitComplement,
@@ -168,3 +263,14 @@ where
         Difference(self.0.difference(rhs), self.1)
     }
 }
+
+impl<T, U, Y> BitSymmetricDifference<Y> for Difference<T, U> {
+    type Output = SymmetricDifference<Difference<T, U>, Y>;
+
+    fn symmetric_difference(self, rhs: Y) -> Self::Output {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl_bit_operators!(impl[T, U] for Difference<T, U>);
+impl_bit_operators!(impl['a, T, U] for &'a Difference<T, U>);
@@ -0,0 +1,754 @@
+use {
+    crate::{
+        complement::Complement, difference::Difference, intersection::Intersection, ops::*,
+        symmetric_difference::SymmetricDifference, union::Union,
+    },
+    alloc::vec::Vec,
+};
+
+/// Growable bit-set that stores bits in heap-allocated words.
+///
+/// Any bit can be set, storage grows to hold largest bit set.
+/// Trailing words without bits set are released on unset,
+/// so two bit-sets with same bits set are equal.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut set = DynBits::empty();
+/// set.set(3);
+/// set.set(1_000);
+/// assert_eq!(set.words().len(), 16);
+///
+/// set.unset(1_000);
+/// assert_eq!(set.words().len(), 1);
+///
+/// let set = set.union([0, 1u64]);
+/// assert!(set.iter_set().eq([3, 64]));
+///
+/// // Fixed-size arrays of words work on either side.
+/// assert_eq!([0b1000u64, 0].intersection(set.clone()), [0b1000, 0]);
+/// assert!([0, 1u64].is_subset_of(&set));
+/// ```
+///
+/// # Panics
+///
+/// Setting bits panics if storage for them cannot be allocated.
+/// This includes unbounded ranges, e.g. `set.set_range(5..)`, that end at `usize::MAX`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DynBits {
+    words: Vec<u64>,
+}
+
+const WORD: usize = 64;
+
+impl DynBits {
+    /// Returns empty bit-set with storage preallocated for `capacity` bits.
+    pub fn with_capacity(capacity: usize) -> Self {
+        DynBits {
+            words: Vec::with_capacity(capacity.saturating_add(WORD - 1) / WORD),
+        }
+    }
+
+    /// Returns words that hold the bits.
+    /// Bit with index `idx` is stored in word `idx / 64` at position `idx % 64`.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Shrinks storage capacity to fit the bits set.
+    pub fn shrink_to_fit(&mut self) {
+        self.words.shrink_to_fit();
+    }
+
+    /// Returns index of the first bit that is not stored.
+    fn len(&self) -> usize {
+        self.words.len() * WORD
+    }
+
+    /// Ensures bit with index `idx` is stored.
+    fn grow(&mut self, idx: usize) {
+        let len = idx / WORD + 1;
+        if self.words.len() < len {
+            // Unbounded ranges, e.g. `5..`, end at `usize::MAX` and cannot be stored.
+            let additional = len - self.words.len();
+            assert!(
+                self.words.try_reserve(additional).is_ok(),
+                "Bit-set capacity exceeded"
+            );
+            self.words.resize(len, 0);
+        }
+    }
+
+    /// Releases trailing words without bits set.
+    fn trim(&mut self) {
+        while let Some(0) = self.words.last() {
+            self.words.pop();
+        }
+    }
+
+    fn from_words(words: Vec<u64>) -> Self {
+        let mut set = DynBits { words };
+        set.trim();
+        set
+    }
+
+    fn union_words(&mut self, rhs: &[u64]) {
+        if self.words.len() < rhs.len() {
+            self.words.resize(rhs.len(), 0);
+        }
+        self.words
+            .iter_mut()
+            .zip(rhs)
+            .for_each(|(lhs, rhs)| *lhs |= *rhs);
+        self.trim();
+    }
+
+    fn intersect_words(&mut self, rhs: &[u64]) {
+        self.words.truncate(rhs.len());
+        self.words
+            .iter_mut()
+            .zip(rhs)
+            .for_each(|(lhs, rhs)| *lhs &= *rhs);
+        self.trim();
+    }
+
+    fn difference_words(&mut self, rhs: &[u64]) {
+        self.words
+            .iter_mut()
+            .zip(rhs)
+            .for_each(|(lhs, rhs)| *lhs &= !*rhs);
+        self.trim();
+    }
+
+    fn symmetric_difference_words(&mut self, rhs: &[u64]) {
+        if self.words.len() < rhs.len() {
+            self.words.resize(rhs.len(), 0);
+        }
+        self.words
+            .iter_mut()
+            .zip(rhs)
+            .for_each(|(lhs, rhs)| *lhs ^= *rhs);
+        self.trim();
+    }
+
+    fn is_subset_of_words(&self, rhs: &[u64]) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(i, lhs)| match rhs.get(i) {
+                None => *lhs == 0,
+                Some(rhs) => *lhs & !*rhs == 0,
+            })
+    }
+
+    fn is_disjoint_words(&self, rhs: &[u64]) -> bool {
+        self.words
+            .iter()
+            .zip(rhs)
+            .all(|(lhs, rhs)| *lhs & *rhs == 0)
+    }
+}
+
+impl BitEmpty for DynBits {
+    fn empty() -> Self {
+        DynBits { words: Vec::new() }
+    }
+}
+
+impl BitTest for DynBits {
+    #[inline]
+    fn test(&self, idx: usize) -> bool {
+        match self.words.get(idx / WORD) {
+            None => false,
+            Some(word) => word.test(idx % WORD),
+        }
+    }
+}
+
+impl BitTestAll for DynBits {
+    #[inline]
+    fn test_all(&self) -> bool {
+        // Bits up to `usize::MAX` cannot be stored.
+        false
+    }
+}
+
+impl BitTestNone for DynBits {
+    #[inline]
+    fn test_none(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+}
+
+impl BitSetLimit for DynBits {
+    const MAX_SET_INDEX: usize = usize::MAX;
+}
+
+impl BitSet for DynBits {
+    #[inline]
+    unsafe fn set_unchecked(&mut self, idx: usize) {
+        self.grow(idx);
+        self.words[idx / WORD].set_unchecked(idx % WORD);
+    }
+}
+
+impl BitUnsetLimit for DynBits {
+    const MAX_UNSET_INDEX: usize = usize::MAX;
+}
+
+impl BitUnset for DynBits {
+    #[inline]
+    unsafe fn unset_unchecked(&mut self, idx: usize) {
+        if let Some(word) = self.words.get_mut(idx / WORD) {
+            word.unset_unchecked(idx % WORD);
+            self.trim();
+        }
+    }
+}
+
+impl BitSetRange for DynBits {
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        debug_assert!(first <= last);
+        self.grow(last);
+        let (i_first, i_last) = (first / WORD, last / WORD);
+
+        for i in i_first..=i_last {
+            let lower = if i == i_first { first % WORD } else { 0 };
+            let upper = if i == i_last { last % WORD } else { WORD - 1 };
+            self.words[i].set_range_unchecked(lower, upper);
+        }
+    }
+}
+
+impl BitUnsetRange for DynBits {
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        if first < self.len() {
+            let last = last.min(self.len() - 1);
+            let (i_first, i_last) = (first / WORD, last / WORD);
+
+            for i in i_first..=i_last {
+                let lower = if i == i_first { first % WORD } else { 0 };
+                let upper = if i == i_last { last % WORD } else { WORD - 1 };
+                self.words[i].unset_range_unchecked(lower, upper);
+            }
+            self.trim();
+        }
+    }
+}
+
+impl BitTestRange for DynBits {
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::range_bounds(&range, usize::MAX) {
+            None => true,
+            Some((_, last)) if last >= self.len() => false,
+            Some((first, last)) => {
+                let (i_first, i_last) = (first / WORD, last / WORD);
+
+                (i_first..=i_last).all(|i| {
+                    let lower = if i == i_first { first % WORD } else { 0 };
+                    let upper = if i == i_last { last % WORD } else { WORD - 1 };
+                    self.words[i].test_range_all(lower..=upper)
+                })
+            }
+        }
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.find_set_in_range(range).is_some()
+    }
+}
+
+impl BitSearch for DynBits {
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        let i = lower_bound / WORD;
+        let first = self.words.get(i)?.find_first_set(lower_bound % WORD);
+
+        match first {
+            Some(idx) => Some(i * WORD + idx),
+            None => self.words[i + 1..]
+                .iter()
+                .position(|word| *word != 0)
+                .map(|offset| {
+                    let i = i + 1 + offset;
+                    i * WORD + self.words[i].trailing_zeros() as usize
+                }),
+        }
+    }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        Some(self.words.get(idx).copied().unwrap_or(0))
+    }
+}
+
+impl BitSearchRev for DynBits {
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        if self.words.is_empty() {
+            return None;
+        }
+
+        let upper_bound = upper_bound.min(self.len() - 1);
+        let i = upper_bound / WORD;
+
+        match self.words[i].find_last_set(upper_bound % WORD) {
+            Some(idx) => Some(i * WORD + idx),
+            None => self.words[..i]
+                .iter()
+                .rposition(|word| *word != 0)
+                .map(|i| i * WORD + WORD - 1 - self.words[i].leading_zeros() as usize),
+        }
+    }
+}
+
+impl BitSearchUnset for DynBits {
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        let i = lower_bound / WORD;
+        let word = match self.words.get(i) {
+            None => return Some(lower_bound),
+            Some(word) => word,
+        };
+
+        match word.find_first_unset(lower_bound % WORD) {
+            Some(idx) if idx < WORD => Some(i * WORD + idx),
+            _ => match self.words[i + 1..].iter().position(|word| *word != !0) {
+                None => Some(self.len()),
+                Some(offset) => {
+                    let i = i + 1 + offset;
+                    Some(i * WORD + self.words[i].trailing_ones() as usize)
+                }
+            },
+        }
+    }
+}
+
+impl BitSearchRun for DynBits {}
+
+impl BitCount for DynBits {
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        if self.words.is_empty() {
+            return 0;
+        }
+
+        let (start, end) = match crate::inclusive_bounds(&range, self.len() - 1) {
+            None => return 0,
+            Some(bounds) => bounds,
+        };
+
+        let first = start / WORD;
+        let last = end / WORD;
+
+        (first..=last)
+            .map(|i| {
+                let lower = if i == first { start % WORD } else { 0 };
+                let upper = if i == last { end % WORD } else { WORD - 1 };
+                self.words[i].count_in_range(lower..=upper)
+            })
+            .sum()
+    }
+
+    #[inline]
+    fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+}
+
+impl BitRankSelect for DynBits {
+    fn select(&self, mut n: usize) -> Option<usize> {
+        for (i, word) in self.words.iter().enumerate() {
+            let count = word.count_ones();
+            if n < count {
+                return Some(i * WORD + word.select(n)?);
+            }
+            n -= count;
+        }
+        None
+    }
+}
+
+impl BitComplement for DynBits {
+    type Output = Complement<Self>;
+
+    fn complement(self) -> Complement<Self> {
+        Complement(self)
+    }
+}
+
+impl BitUnion for DynBits {
+    type Output = Self;
+
+    fn union(mut self, rhs: Self) -> Self {
+        self.union_assign(&rhs);
+        self
+    }
+}
+
+impl BitUnion<Complement<DynBits>> for DynBits {
+    type Output = Complement<Self>;
+
+    fn union(self, rhs: Complement<Self>) -> Complement<Self> {
+        Complement(rhs.0.difference(self))
+    }
+}
+
+impl<const N: usize> BitUnion<[u64; N]> for DynBits {
+    type Output = Self;
+
+    fn union(mut self, rhs: [u64; N]) -> Self {
+        self.union_assign(&rhs);
+        self
+    }
+}
+
+impl BitIntersection for DynBits {
+    type Output = Self;
+
+    fn intersection(mut self, rhs: Self) -> Self {
+        self.intersect_assign(&rhs);
+        self
+    }
+}
+
+impl BitIntersection<Complement<DynBits>> for DynBits {
+    type Output = Self;
+
+    fn intersection(mut self, rhs: Complement<Self>) -> Self {
+        self.intersect_assign(&rhs);
+        self
+    }
+}
+
+impl<const N: usize> BitIntersection<[u64; N]> for DynBits {
+    type Output = Self;
+
+    fn intersection(mut self, rhs: [u64; N]) -> Self {
+        self.intersect_assign(&rhs);
+        self
+    }
+}
+
+impl BitDifference for DynBits {
+    type Output = Self;
+
+    fn difference(mut self, rhs: Self) -> Self {
+        self.difference_assign(&rhs);
+        self
+    }
+}
+
+impl BitDifference<Complement<DynBits>> for DynBits {
+    type Output = Self;
+
+    fn difference(mut self, rhs: Complement<Self>) -> Self {
+        self.difference_assign(&rhs);
+        self
+    }
+}
+
+impl<const N: usize> BitDifference<[u64; N]> for DynBits {
+    type Output = Self;
+
+    fn difference(mut self, rhs: [u64; N]) -> Self {
+        self.difference_assign(&rhs);
+        self
+    }
+}
+
+impl BitSymmetricDifference for DynBits {
+    type Output = Self;
+
+    fn symmetric_difference(mut self, rhs: Self) -> Self {
+        self.symmetric_difference_assign(&rhs);
+        self
+    }
+}
+
+impl BitSymmetricDifference<Complement<DynBits>> for DynBits {
+    type Output = Complement<Self>;
+
+    fn symmetric_difference(mut self, rhs: Complement<Self>) -> Complement<Self> {
+        self.symmetric_difference_assign(&rhs.0);
+        Complement(self)
+    }
+}
+
+impl<const N: usize> BitSymmetricDifference<[u64; N]> for DynBits {
+    type Output = Self;
+
+    fn symmetric_difference(mut self, rhs: [u64; N]) -> Self {
+        self.symmetric_difference_assign(&rhs);
+        self
+    }
+}
+
+impl_bit_operators!(impl[] for DynBits);
+impl_bit_operators!(impl['a] for &'a DynBits);
+
+impl BitUnionAssign for DynBits {
+    fn union_assign(&mut self, rhs: &Self) {
+        self.union_words(&rhs.words);
+    }
+}
+
+impl<const N: usize> BitUnionAssign<[u64; N]> for DynBits {
+    fn union_assign(&mut self, rhs: &[u64; N]) {
+        self.union_words(rhs);
+    }
+}
+
+impl BitIntersectionAssign for DynBits {
+    fn intersect_assign(&mut self, rhs: &Self) {
+        self.intersect_words(&rhs.words);
+    }
+}
+
+impl<const N: usize> BitIntersectionAssign<[u64; N]> for DynBits {
+    fn intersect_assign(&mut self, rhs: &[u64; N]) {
+        self.intersect_words(rhs);
+    }
+}
+
+impl BitIntersectionAssign<Complement<DynBits>> for DynBits {
+    fn intersect_assign(&mut self, rhs: &Complement<Self>) {
+        self.difference_assign(&rhs.0);
+    }
+}
+
+impl BitDifferenceAssign for DynBits {
+    fn difference_assign(&mut self, rhs: &Self) {
+        self.difference_words(&rhs.words);
+    }
+}
+
+impl<const N: usize> BitDifferenceAssign<[u64; N]> for DynBits {
+    fn difference_assign(&mut self, rhs: &[u64; N]) {
+        self.difference_words(rhs);
+    }
+}
+
+impl BitDifferenceAssign<Complement<DynBits>> for DynBits {
+    fn difference_assign(&mut self, rhs: &Complement<Self>) {
+        self.intersect_assign(&rhs.0);
+    }
+}
+
+impl BitSymmetricDifferenceAssign for DynBits {
+    fn symmetric_difference_assign(&mut self, rhs: &Self) {
+        self.symmetric_difference_words(&rhs.words);
+    }
+}
+
+impl<const N: usize> BitSymmetricDifferenceAssign<[u64; N]> for DynBits {
+    fn symmetric_difference_assign(&mut self, rhs: &[u64; N]) {
+        self.symmetric_difference_words(rhs);
+    }
+}
+
+impl_assign_by_search!(impl[] for DynBits; Union Intersection Difference SymmetricDifference);
+
+impl BitSubset for DynBits {
+    fn is_subset_of(&self, rhs: &Self) -> bool {
+        self.is_subset_of_words(&rhs.words)
+    }
+}
+
+impl<const N: usize> BitSubset<[u64; N]> for DynBits {
+    fn is_subset_of(&self, rhs: &[u64; N]) -> bool {
+        self.is_subset_of_words(rhs)
+    }
+}
+
+impl BitSubset<Complement<DynBits>> for DynBits {
+    fn is_subset_of(&self, rhs: &Complement<Self>) -> bool {
+        self.is_disjoint(&rhs.0)
+    }
+}
+
+impl BitDisjoint for DynBits {
+    fn is_disjoint(&self, rhs: &Self) -> bool {
+        self.is_disjoint_words(&rhs.words)
+    }
+}
+
+impl<const N: usize> BitDisjoint<[u64; N]> for DynBits {
+    fn is_disjoint(&self, rhs: &[u64; N]) -> bool {
+        self.is_disjoint_words(rhs)
+    }
+}
+
+impl BitDisjoint<Complement<DynBits>> for DynBits {
+    fn is_disjoint(&self, rhs: &Complement<Self>) -> bool {
+        self.is_subset_of(&rhs.0)
+    }
+}
+
+impl<const N: usize> BitUnion<DynBits> for [u64; N] {
+    type Output = DynBits;
+
+    fn union(self, rhs: DynBits) -> DynBits {
+        rhs.union(self)
+    }
+}
+
+impl<const N: usize> BitIntersection<DynBits> for [u64; N] {
+    type Output = Self;
+
+    fn intersection(mut self, rhs: DynBits) -> Self {
+        self.intersect_assign(&rhs);
+        self
+    }
+}
+
+impl<const N: usize> BitDifference<DynBits> for [u64; N] {
+    type Output = Self;
+
+    fn difference(mut self, rhs: DynBits) -> Self {
+        self.difference_assign(&rhs);
+        self
+    }
+}
+
+impl<const N: usize> BitSymmetricDifference<DynBits> for [u64; N] {
+    type Output = DynBits;
+
+    fn symmetric_difference(self, rhs: DynBits) -> DynBits {
+        rhs.symmetric_difference(self)
+    }
+}
+
+impl<const N: usize> BitUnionAssign<DynBits> for [u64; N] {
+    fn union_assign(&mut self, rhs: &DynBits) {
+        // Trailing words without bits set are released, so longer `rhs` has bits past the end.
+        assert!(rhs.words.len() <= N, "Bit-set capacity exceeded");
+        self.iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(lhs, rhs)| *lhs |= *rhs);
+    }
+}
+
+impl<const N: usize> BitIntersectionAssign<DynBits> for [u64; N] {
+    fn intersect_assign(&mut self, rhs: &DynBits) {
+        self.iter_mut()
+            .enumerate()
+            .for_each(|(i, lhs)| *lhs &= rhs.words.get(i).copied().unwrap_or(0));
+    }
+}
+
+impl<const N: usize> BitDifferenceAssign<DynBits> for [u64; N] {
+    fn difference_assign(&mut self, rhs: &DynBits) {
+        self.iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(lhs, rhs)| *lhs &= !*rhs);
+    }
+}
+
+impl<const N: usize> BitSymmetricDifferenceAssign<DynBits> for [u64; N] {
+    fn symmetric_difference_assign(&mut self, rhs: &DynBits) {
+        // Trailing words without bits set are released, so longer `rhs` has bits past the end.
+        assert!(rhs.words.len() <= N, "Bit-set capacity exceeded");
+        self.iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(lhs, rhs)| *lhs ^= *rhs);
+    }
+}
+
+impl<const N: usize> BitSubset<DynBits> for [u64; N] {
+    fn is_subset_of(&self, rhs: &DynBits) -> bool {
+        self.iter()
+            .enumerate()
+            .all(|(i, lhs)| *lhs & !rhs.words.get(i).copied().unwrap_or(0) == 0)
+    }
+}
+
+impl<const N: usize> BitDisjoint<DynBits> for [u64; N] {
+    fn is_disjoint(&self, rhs: &DynBits) -> bool {
+        rhs.is_disjoint_words(self)
+    }
+}
+
+impl From<Vec<u64>> for DynBits {
+    fn from(words: Vec<u64>) -> Self {
+        DynBits::from_words(words)
+    }
+}
+
+impl<const N: usize> From<[u64; N]> for DynBits {
+    fn from(words: [u64; N]) -> Self {
+        DynBits::from_words(words.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_util::Rng};
+
+    fn random_words<const N: usize>(rng: &mut Rng) -> [u64; N] {
+        let mut words = [0; N];
+        for word in &mut words {
+            if rng.below(3) > 0 {
+                *word = rng.next() & rng.next();
+            }
+        }
+        words
+    }
+
+    #[test]
+    #[should_panic(expected = "Bit-set capacity exceeded")]
+    fn unbounded_range_panics() {
+        DynBits::empty().set_range(5..);
+    }
+
+    #[test]
+    fn ops_with_arrays_match_words() {
+        let mut rng = Rng::new(16);
+        for _ in 0..500 {
+            let lhs: [u64; 4] = random_words(&mut rng);
+            let short: [u64; 2] = random_words(&mut rng);
+            let dyn_lhs = DynBits::from(lhs);
+            let dyn_short = DynBits::from(short);
+            let widened = [short[0], short[1], 0, 0];
+
+            let map = |f: fn(u64, u64) -> u64| crate::map2_arrays(lhs, widened, f);
+            assert_eq!(
+                lhs.union(dyn_short.clone()),
+                DynBits::from(map(|l, r| l | r))
+            );
+            assert_eq!(lhs.intersection(dyn_short.clone()), map(|l, r| l & r));
+            assert_eq!(lhs.difference(dyn_short.clone()), map(|l, r| l & !r));
+            assert_eq!(
+                lhs.symmetric_difference(dyn_short.clone()),
+                DynBits::from(map(|l, r| l ^ r)),
+            );
+            assert_eq!(
+                dyn_lhs.clone().intersection(short),
+                DynBits::from(map(|l, r| l & r))
+            );
+            assert_eq!(
+                dyn_lhs.clone().difference(short),
+                DynBits::from(map(|l, r| l & !r))
+            );
+
+            assert_eq!(lhs.is_subset_of(&dyn_short), map(|l, r| l & !r) == [0; 4]);
+            assert_eq!(lhs.is_disjoint(&dyn_short), map(|l, r| l & r) == [0; 4]);
+            assert_eq!(short.is_subset_of(&dyn_lhs), dyn_short.is_subset_of(&lhs));
+
+            let mut assigned = short;
+            assigned.intersect_assign(&dyn_lhs);
+            assert_eq!(DynBits::from(assigned), dyn_short.clone().intersection(lhs));
+
+            let mut assigned = lhs;
+            assigned.union_assign(&dyn_short);
+            assigned.symmetric_difference_assign(&dyn_short);
+            assert_eq!(assigned, map(|l, r| (l | r) ^ r));
+        }
+    }
+}
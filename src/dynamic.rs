@@ -0,0 +1,47 @@
+use crate::ops::*;
+
+/// Implements read-only bit-set traits for trait object.
+///
+/// Mutation is left to `DynBitSet` methods, otherwise references to trait objects
+/// would implement `DynBitSet` with `usize::MAX` limit themselves.
+macro_rules! impl_for_dyn {
+    ($($ty:tt)*) => {
+        impl BitTest for $($ty)* {
+            fn test(&self, idx: usize) -> bool {
+                self.dyn_test(idx)
+            }
+        }
+
+        impl BitTestNone for $($ty)* {
+            fn test_none(&self) -> bool {
+                self.dyn_find_first_set(0).is_none()
+            }
+        }
+
+        impl BitSetLimit for $($ty)* {
+            const MAX_SET_INDEX: usize = usize::MAX;
+        }
+
+        impl BitSearch for $($ty)* {
+            fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+                self.dyn_find_first_set(lower_bound)
+            }
+        }
+
+        impl BitCount for $($ty)* {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::inclusive_bounds(&range, self.max_set_index()) {
+                    None => 0,
+                    Some((first, last)) => self.dyn_count_in_range(first, last),
+                }
+            }
+        }
+    };
+}
+
+impl_for_dyn!(dyn DynBitSet + '_);
+impl_for_dyn!(dyn DynBitSet + Send + '_);
+impl_for_dyn!(dyn DynBitSet + Send + Sync + '_);
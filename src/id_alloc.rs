@@ -0,0 +1,379 @@
+use {
+    crate::ops::*,
+    core::fmt::{self, Display},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Error returned when all indices of bit-set are already allocated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CapacityExceeded;
+
+impl Display for CapacityExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Bit-set capacity exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityExceeded {}
+
+/// Allocator of lowest free indices.
+///
+/// Allocated indices are bits set in wrapped bit-set.
+/// Indices are allocated up to `S::MAX_SET_INDEX`
+/// or lower maximum index given to `from_bits_with_max_id`.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut ids = IdAllocator65536::default();
+/// assert_eq!(ids.alloc(), Ok(0));
+/// assert_eq!(ids.alloc(), Ok(1));
+/// assert_eq!(ids.alloc_at_or_after(1000), Ok(1000));
+///
+/// assert!(ids.free(0));
+/// assert!(!ids.free(0));
+/// assert!(!ids.is_allocated(0));
+/// assert_eq!(ids.alloc(), Ok(0));
+///
+/// let mut batch = Vec::new();
+/// ids.alloc_n(3, &mut batch).unwrap();
+/// assert_eq!(batch, [2, 3, 4]);
+/// assert!(ids.bits().iter_set().eq([0, 1, 2, 3, 4, 1000]));
+///
+/// let mut small = IdAllocator::<u8>::new();
+/// assert_eq!(small.alloc_n(9, &mut batch), Err(CapacityExceeded));
+/// assert!(small.bits().test_none());
+/// assert_eq!(small.alloc_at_or_after(8), Err(CapacityExceeded));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IdAllocator<S> {
+    bits: S,
+    max_id: usize,
+}
+
+impl<S> IdAllocator<S>
+where
+    S: BitEmpty + BitSetLimit,
+{
+    /// Returns allocator with no indices allocated.
+    pub fn new() -> Self {
+        Self::from_bits(S::empty())
+    }
+}
+
+impl<S> Default for IdAllocator<S>
+where
+    S: BitEmpty + BitSetLimit,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> IdAllocator<S>
+where
+    S: BitSetLimit,
+{
+    /// Returns allocator with indices of bits set in `bits` allocated.
+    pub fn from_bits(bits: S) -> Self {
+        Self::from_bits_with_max_id(bits, S::MAX_SET_INDEX)
+    }
+
+    /// Returns allocator with indices of bits set in `bits` allocated
+    /// that allocates indices up to `max_id`.
+    ///
+    /// Bit-sets bound at runtime, e.g. slices, report `MAX_SET_INDEX` of `usize::MAX`,
+    /// so their allocators must be bound explicitly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_id` is greater than `S::MAX_SET_INDEX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut buffer = [0u8; 2];
+    /// let max_id = buffer.len() * 8 - 1;
+    /// let mut ids = IdAllocator::from_bits_with_max_id(&mut buffer[..], max_id);
+    /// for id in 0..16 {
+    ///     assert_eq!(ids.alloc(), Ok(id));
+    /// }
+    /// assert_eq!(ids.alloc(), Err(CapacityExceeded));
+    /// ```
+    pub fn from_bits_with_max_id(bits: S, max_id: usize) -> Self {
+        assert!(max_id <= S::MAX_SET_INDEX, "Bit-set capacity exceeded");
+        IdAllocator { bits, max_id }
+    }
+}
+
+impl<S> IdAllocator<S> {
+    /// Returns maximum index that can be allocated.
+    pub fn max_id(&self) -> usize {
+        self.max_id
+    }
+
+    /// Returns bit-set of allocated indices.
+    pub fn bits(&self) -> &S {
+        &self.bits
+    }
+
+    /// Unwraps bit-set of allocated indices.
+    pub fn into_bits(self) -> S {
+        self.bits
+    }
+}
+
+impl<S> IdAllocator<S>
+where
+    S: BitTest + BitSearchUnset + BitSet + BitUnset,
+{
+    /// Allocates lowest free index.
+    pub fn alloc(&mut self) -> Result<usize, CapacityExceeded> {
+        self.alloc_at_or_after(0)
+    }
+
+    /// Allocates lowest free index not less than `hint`.
+    /// Indices below `hint` are not considered.
+    pub fn alloc_at_or_after(&mut self, hint: usize) -> Result<usize, CapacityExceeded> {
+        let idx = self.find_free(hint).ok_or(CapacityExceeded)?;
+        unsafe {
+            // # Safe
+            // `find_free` returns indices up to `max_id` that is within `MAX_SET_INDEX`.
+            self.bits.set_unchecked(idx);
+        }
+        Ok(idx)
+    }
+
+    /// Allocates `n` lowest free indices and appends them to `ids` in ascending order.
+    /// Nothing is allocated if fewer than `n` indices are free.
+    pub fn alloc_n<E>(&mut self, n: usize, ids: &mut E) -> Result<(), CapacityExceeded>
+    where
+        E: Extend<usize>,
+    {
+        let mut next = 0;
+        for _ in 0..n {
+            next = self.find_free(next).ok_or(CapacityExceeded)? + 1;
+        }
+
+        let mut next = 0;
+        ids.extend((0..n).map(|_| {
+            let idx = self.find_free(next).unwrap();
+            unsafe {
+                // # Safe
+                // `find_free` returns indices up to `max_id` that is within `MAX_SET_INDEX`.
+                self.bits.set_unchecked(idx);
+            }
+            next = idx + 1;
+            idx
+        }));
+        Ok(())
+    }
+
+    /// Frees index.
+    /// Returns `false` if index was not allocated.
+    pub fn free(&mut self, id: usize) -> bool {
+        if self.bits.test(id) {
+            self.bits.unset(id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks if index is allocated.
+    pub fn is_allocated(&self, id: usize) -> bool {
+        self.bits.test(id)
+    }
+
+    fn find_free(&self, lower_bound: usize) -> Option<usize> {
+        self.bits
+            .find_first_unset(lower_bound)
+            .filter(|&idx| idx <= self.max_id)
+    }
+}
+
+/// Index allocated by `GenerationalIdAllocator`.
+///
+/// Generation is incremented each time index is freed,
+/// so handles to freed index do not match handles to reallocated one.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Handle {
+    id: usize,
+    generation: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl Handle {
+    /// Returns allocated index.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns generation of index at the time of allocation.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// Allocator of lowest free indices that hands out ABA-safe handles.
+///
+/// Keeps generation counter for every index that was allocated at least once.
+/// Counters wrap around after `u32::MAX` frees of the same index.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut ids = GenerationalIdAllocator::<Bits4096>::new();
+/// let first = ids.alloc().unwrap();
+/// assert!(ids.is_valid(first));
+/// assert!(ids.free(first));
+///
+/// let second = ids.alloc().unwrap();
+/// assert_eq!(first.id(), second.id());
+/// assert_ne!(first, second);
+/// assert!(!ids.is_valid(first) && !ids.free(first));
+/// assert!(ids.is_valid(second));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct GenerationalIdAllocator<S> {
+    ids: IdAllocator<S>,
+    generations: Vec<u32>,
+}
+
+#[cfg(feature = "alloc")]
+impl<S> GenerationalIdAllocator<S>
+where
+    S: BitEmpty + BitSetLimit,
+{
+    /// Returns allocator with no indices allocated.
+    pub fn new() -> Self {
+        GenerationalIdAllocator {
+            ids: IdAllocator::new(),
+            generations: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> Default for GenerationalIdAllocator<S>
+where
+    S: BitEmpty + BitSetLimit,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> GenerationalIdAllocator<S> {
+    /// Returns bit-set of allocated indices.
+    pub fn bits(&self) -> &S {
+        self.ids.bits()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> GenerationalIdAllocator<S>
+where
+    S: BitTest + BitSearchUnset + BitSet + BitUnset,
+{
+    /// Allocates lowest free index.
+    pub fn alloc(&mut self) -> Result<Handle, CapacityExceeded> {
+        self.alloc_at_or_after(0)
+    }
+
+    /// Allocates lowest free index not less than `hint`.
+    pub fn alloc_at_or_after(&mut self, hint: usize) -> Result<Handle, CapacityExceeded> {
+        let id = self.ids.alloc_at_or_after(hint)?;
+        if id >= self.generations.len() {
+            self.generations.resize(id + 1, 0);
+        }
+
+        Ok(Handle {
+            id,
+            generation: self.generations[id],
+        })
+    }
+
+    /// Frees index of the handle.
+    /// Returns `false` if handle is stale or was never allocated.
+    pub fn free(&mut self, handle: Handle) -> bool {
+        if self.is_valid(handle) {
+            self.ids.free(handle.id);
+            self.generations[handle.id] = handle.generation.wrapping_add(1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks if handle refers to currently allocated index.
+    pub fn is_valid(&self, handle: Handle) -> bool {
+        self.ids.is_allocated(handle.id)
+            && self.generations.get(handle.id) == Some(&handle.generation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::test_util::Rng, crate::Bits4096};
+
+    #[test]
+    fn default_for_layered() {
+        let mut ids = IdAllocator::<Bits4096>::default();
+        assert_eq!(ids.alloc(), Ok(0));
+        #[cfg(feature = "alloc")]
+        assert!(GenerationalIdAllocator::<Bits4096>::default()
+            .bits()
+            .test_none());
+    }
+
+    /// Checks that extended indices are free indices of the model in ascending order.
+    struct Batch<'a>(core::ops::Range<usize>, &'a [bool]);
+
+    impl Extend<usize> for Batch<'_> {
+        fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+            for id in iter {
+                let model = self.1;
+                assert_eq!(self.0.find(|&i| !model[i]), Some(id));
+            }
+        }
+    }
+
+    #[test]
+    fn matches_model() {
+        let mut rng = Rng::new(23);
+        let mut ids = IdAllocator::<Bits4096>::new();
+        let mut model = [false; 4096];
+
+        for _ in 0..20000 {
+            let id = rng.below(4096);
+            match rng.below(3) {
+                0 => {
+                    let expected = (id..4096).find(|&i| !model[i]);
+                    assert_eq!(ids.alloc_at_or_after(id).ok(), expected);
+                    if let Some(i) = expected {
+                        model[i] = true;
+                    }
+                }
+                1 => assert_eq!(ids.free(id), core::mem::replace(&mut model[id], false)),
+                _ => assert_eq!(ids.is_allocated(id), model[id]),
+            }
+        }
+
+        let free = model.iter().filter(|&&x| !x).count();
+        let mut batch = Batch(0..4096, &model);
+        assert_eq!(ids.alloc_n(free + 1, &mut batch), Err(CapacityExceeded));
+        assert_eq!(ids.alloc_n(free, &mut batch), Ok(()));
+        assert!(batch.0.all(|i| model[i]));
+        assert_eq!(ids.alloc(), Err(CapacityExceeded));
+    }
+}
@@ -1,12 +1,19 @@
 use crate::{
     complement::Complement, difference::Difference, intersection::Intersection, ops::*,
-    union::Union,
+    symmetric_difference::SymmetricDifference, union::Union,
 };
 
 #[cfg(feature = "alloc")]
-use alloc::boxed::Box;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    rc::Rc,
+};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 
-impl<T> BitTest for &'_ T
+impl<T: ?Sized> BitTest for &'_ T
 where
     T: BitTest,
 {
@@ -15,7 +22,7 @@ where
     }
 }
 
-impl<T> BitTestNone for &'_ T
+impl<T: ?Sized> BitTestNone for &'_ T
 where
     T: BitTestNone,
 {
@@ -24,7 +31,7 @@ where
     }
 }
 
-impl<T> BitTestAll for &'_ T
+impl<T: ?Sized> BitTestAll for &'_ T
 where
     T: BitTestAll,
 {
@@ -33,30 +40,109 @@ where
     }
 }
 
-impl<T> BitSetLimit for &'_ T
+impl<T: ?Sized> BitSetLimit for &'_ T
 where
     T: BitSetLimit,
 {
     const MAX_SET_INDEX: usize = T::MAX_SET_INDEX;
 }
 
-impl<T> BitUnsetLimit for &'_ T
+impl<T: ?Sized> BitUnsetLimit for &'_ T
 where
     T: BitUnsetLimit,
 {
     const MAX_UNSET_INDEX: usize = T::MAX_UNSET_INDEX;
 }
 
-impl<T> BitSearch for &'_ T
+impl<T: ?Sized> BitTestRange for &'_ T
+where
+    T: BitTestRange,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::test_range_all(*self, range)
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::test_range_any(*self, range)
+    }
+}
+
+impl<T: ?Sized> BitSearch for &'_ T
 where
     T: BitSearch,
 {
     fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
         T::find_first_set(*self, lower_bound)
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        T::word64(*self, idx)
+    }
 }
 
-impl<T> BitComplement for &'_ T {
+impl<T: ?Sized> BitSearchRev for &'_ T
+where
+    T: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        T::find_last_set(*self, upper_bound)
+    }
+}
+
+impl<T: ?Sized> BitSearchUnset for &'_ T
+where
+    T: BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        T::find_first_unset(*self, lower_bound)
+    }
+}
+
+impl<T: ?Sized> BitSearchRun for &'_ T
+where
+    T: BitSearchRun,
+{
+    fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        T::find_first_run_set(*self, len, lower_bound)
+    }
+
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        T::find_first_run_unset(*self, len, lower_bound)
+    }
+
+    fn find_aligned_free(&self, order: u32) -> Option<usize> {
+        T::find_aligned_free(*self, order)
+    }
+}
+
+impl<T: ?Sized> BitCount for &'_ T
+where
+    T: BitCount,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::count_in_range(*self, range)
+    }
+}
+
+impl<T: ?Sized> BitRankSelect for &'_ T
+where
+    T: BitRankSelect,
+{
+    fn select(&self, n: usize) -> Option<usize> {
+        T::select(*self, n)
+    }
+}
+
+impl<T: ?Sized> BitComplement for &'_ T {
     type Output = Complement<Self>;
 
     fn complement(self) -> Complement<Self> {
@@ -64,7 +150,7 @@ impl<T> BitComplement for &'_ T {
     }
 }
 
-impl<T, U> BitUnion<U> for &'_ T {
+impl<T: ?Sized, U> BitUnion<U> for &'_ T {
     type Output = Union<Self, U>;
 
     fn union(self, rhs: U) -> Union<Self, U> {
@@ -72,7 +158,7 @@ impl<T, U> BitUnion<U> for &'_ T {
     }
 }
 
-impl<T, U> BitIntersection<U> for &'_ T {
+impl<T: ?Sized, U> BitIntersection<U> for &'_ T {
     type Output = Intersection<Self, U>;
 
     fn intersection(self, rhs: U) -> Intersection<Self, U> {
@@ -80,7 +166,7 @@ impl<T, U> BitIntersection<U> for &'_ T {
     }
 }
 
-impl<T, U> BitDifference<U> for &'_ T {
+impl<T: ?Sized, U> BitDifference<U> for &'_ T {
     type Output = Difference<Self, U>;
 
     fn difference(self, rhs: U) -> Difference<Self, U> {
@@ -88,7 +174,15 @@ impl<T, U> BitDifference<U> for &'_ T {
     }
 }
 
-impl<T, U> BitSubset<U> for &'_ T
+impl<T: ?Sized, U> BitSymmetricDifference<U> for &'_ T {
+    type Output = SymmetricDifference<Self, U>;
+
+    fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl<T: ?Sized, U> BitSubset<U> for &'_ T
 where
     T: BitSubset<U>,
 {
@@ -97,7 +191,7 @@ where
     }
 }
 
-impl<T, U> BitDisjoint<U> for &'_ T
+impl<T: ?Sized, U> BitDisjoint<U> for &'_ T
 where
     T: BitDisjoint<U>,
 {
@@ -106,7 +200,7 @@ where
     }
 }
 
-impl<T> BitTest for &'_ mut T
+impl<T: ?Sized> BitTest for &'_ mut T
 where
     T: BitTest,
 {
@@ -115,7 +209,7 @@ where
     }
 }
 
-impl<T> BitTestNone for &'_ mut T
+impl<T: ?Sized> BitTestNone for &'_ mut T
 where
     T: BitTestNone,
 {
@@ -124,7 +218,7 @@ where
     }
 }
 
-impl<T> BitTestAll for &'_ mut T
+impl<T: ?Sized> BitTestAll for &'_ mut T
 where
     T: BitTestAll,
 {
@@ -133,14 +227,14 @@ where
     }
 }
 
-impl<T> BitSetLimit for &'_ mut T
+impl<T: ?Sized> BitSetLimit for &'_ mut T
 where
     T: BitSetLimit,
 {
     const MAX_SET_INDEX: usize = T::MAX_SET_INDEX;
 }
 
-impl<T> BitSet for &'_ mut T
+impl<T: ?Sized> BitSet for &'_ mut T
 where
     T: BitSet,
 {
@@ -149,14 +243,14 @@ where
     }
 }
 
-impl<T> BitUnsetLimit for &'_ mut T
+impl<T: ?Sized> BitUnsetLimit for &'_ mut T
 where
     T: BitUnsetLimit,
 {
     const MAX_UNSET_INDEX: usize = T::MAX_UNSET_INDEX;
 }
 
-impl<T> BitUnset for &'_ mut T
+impl<T: ?Sized> BitUnset for &'_ mut T
 where
     T: BitUnset,
 {
@@ -165,16 +259,113 @@ where
     }
 }
 
-impl<T> BitSearch for &'_ mut T
+impl<T: ?Sized> BitTestRange for &'_ mut T
+where
+    T: BitTestRange,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::test_range_all(*self, range)
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::test_range_any(*self, range)
+    }
+}
+
+impl<T: ?Sized> BitSetRange for &'_ mut T
+where
+    T: BitSetRange,
+{
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        T::set_range_unchecked(*self, first, last)
+    }
+}
+
+impl<T: ?Sized> BitUnsetRange for &'_ mut T
+where
+    T: BitUnsetRange,
+{
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        T::unset_range_unchecked(*self, first, last)
+    }
+}
+
+impl<T: ?Sized> BitSearch for &'_ mut T
 where
     T: BitSearch,
 {
     fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
         T::find_first_set(*self, lower_bound)
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        T::word64(*self, idx)
+    }
+}
+
+impl<T: ?Sized> BitSearchRev for &'_ mut T
+where
+    T: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        T::find_last_set(*self, upper_bound)
+    }
+}
+
+impl<T: ?Sized> BitSearchUnset for &'_ mut T
+where
+    T: BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        T::find_first_unset(*self, lower_bound)
+    }
+}
+
+impl<T: ?Sized> BitSearchRun for &'_ mut T
+where
+    T: BitSearchRun,
+{
+    fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        T::find_first_run_set(*self, len, lower_bound)
+    }
+
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        T::find_first_run_unset(*self, len, lower_bound)
+    }
+
+    fn find_aligned_free(&self, order: u32) -> Option<usize> {
+        T::find_aligned_free(*self, order)
+    }
+}
+
+impl<T: ?Sized> BitCount for &'_ mut T
+where
+    T: BitCount,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::count_in_range(*self, range)
+    }
+}
+
+impl<T: ?Sized> BitRankSelect for &'_ mut T
+where
+    T: BitRankSelect,
+{
+    fn select(&self, n: usize) -> Option<usize> {
+        T::select(*self, n)
+    }
 }
 
-impl<T> BitComplement for &'_ mut T {
+impl<T: ?Sized> BitComplement for &'_ mut T {
     type Output = Complement<Self>;
 
     fn complement(self) -> Complement<Self> {
@@ -182,7 +373,7 @@ impl<T> BitComplement for &'_ mut T {
     }
 }
 
-impl<T, U> BitUnion<U> for &'_ mut T {
+impl<T: ?Sized, U> BitUnion<U> for &'_ mut T {
     type Output = Union<Self, U>;
 
     fn union(self, rhs: U) -> Union<Self, U> {
@@ -190,7 +381,7 @@ impl<T, U> BitUnion<U> for &'_ mut T {
     }
 }
 
-impl<T, U> BitIntersection<U> for &'_ mut T {
+impl<T: ?Sized, U> BitIntersection<U> for &'_ mut T {
     type Output = Intersection<Self, U>;
 
     fn intersection(self, rhs: U) -> Intersection<Self, U> {
@@ -198,7 +389,7 @@ impl<T, U> BitIntersection<U> for &'_ mut T {
     }
 }
 
-impl<T, U> BitDifference<U> for &'_ mut T {
+impl<T: ?Sized, U> BitDifference<U> for &'_ mut T {
     type Output = Difference<Self, U>;
 
     fn difference(self, rhs: U) -> Difference<Self, U> {
@@ -206,7 +397,51 @@ impl<T, U> BitDifference<U> for &'_ mut T {
     }
 }
 
-impl<T, U> BitSubset<U> for &'_ mut T
+impl<T: ?Sized, U> BitSymmetricDifference<U> for &'_ mut T {
+    type Output = SymmetricDifference<Self, U>;
+
+    fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl<T: ?Sized, U> BitUnionAssign<U> for &'_ mut T
+where
+    T: BitUnionAssign<U>,
+{
+    fn union_assign(&mut self, rhs: &U) {
+        T::union_assign(*self, rhs)
+    }
+}
+
+impl<T: ?Sized, U> BitIntersectionAssign<U> for &'_ mut T
+where
+    T: BitIntersectionAssign<U>,
+{
+    fn intersect_assign(&mut self, rhs: &U) {
+        T::intersect_assign(*self, rhs)
+    }
+}
+
+impl<T: ?Sized, U> BitDifferenceAssign<U> for &'_ mut T
+where
+    T: BitDifferenceAssign<U>,
+{
+    fn difference_assign(&mut self, rhs: &U) {
+        T::difference_assign(*self, rhs)
+    }
+}
+
+impl<T: ?Sized, U> BitSymmetricDifferenceAssign<U> for &'_ mut T
+where
+    T: BitSymmetricDifferenceAssign<U>,
+{
+    fn symmetric_difference_assign(&mut self, rhs: &U) {
+        T::symmetric_difference_assign(*self, rhs)
+    }
+}
+
+impl<T: ?Sized, U> BitSubset<U> for &'_ mut T
 where
     T: BitSubset<U>,
 {
@@ -215,7 +450,7 @@ where
     }
 }
 
-impl<T, U> BitDisjoint<U> for &'_ mut T
+impl<T: ?Sized, U> BitDisjoint<U> for &'_ mut T
 where
     T: BitDisjoint<U>,
 {
@@ -245,7 +480,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitTest for Box<T>
+impl<T: ?Sized> BitTest for Box<T>
 where
     T: BitTest,
 {
@@ -255,7 +490,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitTestNone for Box<T>
+impl<T: ?Sized> BitTestNone for Box<T>
 where
     T: BitTestNone,
 {
@@ -265,7 +500,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitTestAll for Box<T>
+impl<T: ?Sized> BitTestAll for Box<T>
 where
     T: BitTestAll,
 {
@@ -275,7 +510,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitSetLimit for Box<T>
+impl<T: ?Sized> BitSetLimit for Box<T>
 where
     T: BitSetLimit,
 {
@@ -283,7 +518,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitSet for Box<T>
+impl<T: ?Sized> BitSet for Box<T>
 where
     T: BitSet,
 {
@@ -293,7 +528,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitUnsetLimit for Box<T>
+impl<T: ?Sized> BitUnsetLimit for Box<T>
 where
     T: BitUnsetLimit,
 {
@@ -301,7 +536,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitUnset for Box<T>
+impl<T: ?Sized> BitUnset for Box<T>
 where
     T: BitUnset,
 {
@@ -311,17 +546,122 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitSearch for Box<T>
+impl<T: ?Sized> BitTestRange for Box<T>
+where
+    T: BitTestRange,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::test_range_all(&**self, range)
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::test_range_any(&**self, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitSetRange for Box<T>
+where
+    T: BitSetRange,
+{
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        T::set_range_unchecked(&mut **self, first, last)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitUnsetRange for Box<T>
+where
+    T: BitUnsetRange,
+{
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        T::unset_range_unchecked(&mut **self, first, last)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitSearch for Box<T>
 where
     T: BitSearch,
 {
     fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
         T::find_first_set(&**self, lower_bound)
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        T::word64(&**self, idx)
+    }
 }
 
 #[cfg(feature = "alloc")]
-impl<T> BitComplement for Box<T> {
+impl<T: ?Sized> BitSearchRev for Box<T>
+where
+    T: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        T::find_last_set(&**self, upper_bound)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitSearchUnset for Box<T>
+where
+    T: BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        T::find_first_unset(&**self, lower_bound)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitSearchRun for Box<T>
+where
+    T: BitSearchRun,
+{
+    fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        T::find_first_run_set(&**self, len, lower_bound)
+    }
+
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        T::find_first_run_unset(&**self, len, lower_bound)
+    }
+
+    fn find_aligned_free(&self, order: u32) -> Option<usize> {
+        T::find_aligned_free(&**self, order)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitCount for Box<T>
+where
+    T: BitCount,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        T::count_in_range(&**self, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitRankSelect for Box<T>
+where
+    T: BitRankSelect,
+{
+    fn select(&self, n: usize) -> Option<usize> {
+        T::select(&**self, n)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> BitComplement for Box<T> {
     type Output = Complement<Self>;
 
     fn complement(self) -> Complement<Self> {
@@ -330,7 +670,7 @@ impl<T> BitComplement for Box<T> {
 }
 
 #[cfg(feature = "alloc")]
-impl<T, U> BitUnion<U> for Box<T> {
+impl<T: ?Sized, U> BitUnion<U> for Box<T> {
     type Output = Union<Self, U>;
 
     fn union(self, rhs: U) -> Union<Self, U> {
@@ -339,7 +679,7 @@ impl<T, U> BitUnion<U> for Box<T> {
 }
 
 #[cfg(feature = "alloc")]
-impl<T, U> BitIntersection<U> for Box<T> {
+impl<T: ?Sized, U> BitIntersection<U> for Box<T> {
     type Output = Intersection<Self, U>;
 
     fn intersection(self, rhs: U) -> Intersection<Self, U> {
@@ -348,7 +688,7 @@ impl<T, U> BitIntersection<U> for Box<T> {
 }
 
 #[cfg(feature = "alloc")]
-impl<T, U> BitDifference<U> for Box<T> {
+impl<T: ?Sized, U> BitDifference<U> for Box<T> {
     type Output = Difference<Self, U>;
 
     fn difference(self, rhs: U) -> Difference<Self, U> {
@@ -357,7 +697,56 @@ impl<T, U> BitDifference<U> for Box<T> {
 }
 
 #[cfg(feature = "alloc")]
-impl<T, U> BitSubset<U> for Box<T>
+impl<T: ?Sized, U> BitSymmetricDifference<U> for Box<T> {
+    type Output = SymmetricDifference<Self, U>;
+
+    fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> BitUnionAssign<Box<U>> for Box<T>
+where
+    T: BitUnionAssign<U>,
+{
+    fn union_assign(&mut self, rhs: &Box<U>) {
+        T::union_assign(&mut **self, &**rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> BitIntersectionAssign<Box<U>> for Box<T>
+where
+    T: BitIntersectionAssign<U>,
+{
+    fn intersect_assign(&mut self, rhs: &Box<U>) {
+        T::intersect_assign(&mut **self, &**rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> BitDifferenceAssign<Box<U>> for Box<T>
+where
+    T: BitDifferenceAssign<U>,
+{
+    fn difference_assign(&mut self, rhs: &Box<U>) {
+        T::difference_assign(&mut **self, &**rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> BitSymmetricDifferenceAssign<Box<U>> for Box<T>
+where
+    T: BitSymmetricDifferenceAssign<U>,
+{
+    fn symmetric_difference_assign(&mut self, rhs: &Box<U>) {
+        T::symmetric_difference_assign(&mut **self, &**rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, U> BitSubset<U> for Box<T>
 where
     T: BitSubset<U>,
 {
@@ -367,7 +756,7 @@ where
 }
 
 #[cfg(feature = "alloc")]
-impl<T, U> BitDisjoint<U> for Box<T>
+impl<T: ?Sized, U> BitDisjoint<U> for Box<T>
 where
     T: BitDisjoint<U>,
 {
@@ -375,3 +764,686 @@ where
         T::is_disjoint(&**self, rhs)
     }
 }
+
+/// Implements bit-set traits for shared pointer.
+///
+/// Mutation clones shared bit-set unless pointer is unique,
+/// and only when operation would actually change it.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// use std::{borrow::Cow, rc::Rc};
+///
+/// let mut cached = Rc::new(Bits1048576::empty());
+/// cached.set(1000);
+///
+/// let mut local = Rc::clone(&cached);
+/// local.set(1000);
+/// assert!(Rc::ptr_eq(&local, &cached), "Bit is set already, no copy is made");
+///
+/// local.set(2000);
+/// assert!(!Rc::ptr_eq(&local, &cached));
+/// assert!(cached.iter_set().eq([1000]));
+/// assert!(local.iter_set().eq([1000, 2000]));
+///
+/// let words = [0b101u64];
+/// let mut cow = Cow::Borrowed(&words[..]);
+/// cow.set(2);
+/// assert!(matches!(cow, Cow::Borrowed(_)));
+/// cow.set(100);
+/// assert!(cow.iter_set().eq([0, 2, 100]));
+/// assert_eq!(cow.len(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+macro_rules! impl_for_shared {
+    ($ptr:ident) => {
+        impl<T> BitEmpty for $ptr<T>
+        where
+            T: BitEmpty,
+        {
+            fn empty() -> Self {
+                $ptr::new(T::empty())
+            }
+        }
+
+        impl<T> BitFull for $ptr<T>
+        where
+            T: BitFull,
+        {
+            fn full() -> Self {
+                $ptr::new(T::full())
+            }
+        }
+
+        impl<T: ?Sized> BitTest for $ptr<T>
+        where
+            T: BitTest,
+        {
+            fn test(&self, idx: usize) -> bool {
+                T::test(&**self, idx)
+            }
+        }
+
+        impl<T: ?Sized> BitTestNone for $ptr<T>
+        where
+            T: BitTestNone,
+        {
+            fn test_none(&self) -> bool {
+                T::test_none(&**self)
+            }
+        }
+
+        impl<T: ?Sized> BitTestAll for $ptr<T>
+        where
+            T: BitTestAll,
+        {
+            fn test_all(&self) -> bool {
+                T::test_all(&**self)
+            }
+        }
+
+        impl<T: ?Sized> BitSetLimit for $ptr<T>
+        where
+            T: BitSetLimit,
+        {
+            const MAX_SET_INDEX: usize = T::MAX_SET_INDEX;
+        }
+
+        impl<T> BitSet for $ptr<T>
+        where
+            T: BitSet + BitTest + Clone,
+        {
+            unsafe fn set_unchecked(&mut self, idx: usize) {
+                if !T::test(&**self, idx) {
+                    T::set_unchecked($ptr::make_mut(self), idx)
+                }
+            }
+        }
+
+        impl<T: ?Sized> BitUnsetLimit for $ptr<T>
+        where
+            T: BitUnsetLimit,
+        {
+            const MAX_UNSET_INDEX: usize = T::MAX_UNSET_INDEX;
+        }
+
+        impl<T> BitUnset for $ptr<T>
+        where
+            T: BitUnset + BitTest + Clone,
+        {
+            unsafe fn unset_unchecked(&mut self, idx: usize) {
+                if T::test(&**self, idx) {
+                    T::unset_unchecked($ptr::make_mut(self), idx)
+                }
+            }
+        }
+
+        impl<T: ?Sized> BitTestRange for $ptr<T>
+        where
+            T: BitTestRange,
+        {
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                T::test_range_all(&**self, range)
+            }
+
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                T::test_range_any(&**self, range)
+            }
+        }
+
+        impl<T> BitSetRange for $ptr<T>
+        where
+            T: BitSetRange + BitTest + BitTestRange + Clone,
+        {
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                if !T::test_range_all(&**self, first..=last) {
+                    T::set_range_unchecked($ptr::make_mut(self), first, last)
+                }
+            }
+        }
+
+        impl<T> BitUnsetRange for $ptr<T>
+        where
+            T: BitUnsetRange + BitTest + BitTestRange + Clone,
+        {
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                if T::test_range_any(&**self, first..=last) {
+                    T::unset_range_unchecked($ptr::make_mut(self), first, last)
+                }
+            }
+        }
+
+        impl<T: ?Sized> BitSearch for $ptr<T>
+        where
+            T: BitSearch,
+        {
+            fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+                T::find_first_set(&**self, lower_bound)
+            }
+
+            fn word64(&self, idx: usize) -> Option<u64> {
+                T::word64(&**self, idx)
+            }
+        }
+
+        impl<T: ?Sized> BitSearchRev for $ptr<T>
+        where
+            T: BitSearchRev,
+        {
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                T::find_last_set(&**self, upper_bound)
+            }
+        }
+
+        impl<T: ?Sized> BitSearchUnset for $ptr<T>
+        where
+            T: BitSearchUnset,
+        {
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                T::find_first_unset(&**self, lower_bound)
+            }
+        }
+
+        impl<T: ?Sized> BitSearchRun for $ptr<T>
+        where
+            T: BitSearchRun,
+        {
+            fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                T::find_first_run_set(&**self, len, lower_bound)
+            }
+
+            fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                T::find_first_run_unset(&**self, len, lower_bound)
+            }
+
+            fn find_aligned_free(&self, order: u32) -> Option<usize> {
+                T::find_aligned_free(&**self, order)
+            }
+        }
+
+        impl<T: ?Sized> BitCount for $ptr<T>
+        where
+            T: BitCount,
+        {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                T::count_in_range(&**self, range)
+            }
+        }
+
+        impl<T: ?Sized> BitRankSelect for $ptr<T>
+        where
+            T: BitRankSelect,
+        {
+            fn select(&self, n: usize) -> Option<usize> {
+                T::select(&**self, n)
+            }
+        }
+
+        impl<T: ?Sized> BitComplement for $ptr<T> {
+            type Output = Complement<Self>;
+
+            fn complement(self) -> Complement<Self> {
+                Complement(self)
+            }
+        }
+
+        impl<T: ?Sized, U> BitUnion<U> for $ptr<T> {
+            type Output = Union<Self, U>;
+
+            fn union(self, rhs: U) -> Union<Self, U> {
+                Union(self, rhs)
+            }
+        }
+
+        impl<T: ?Sized, U> BitIntersection<U> for $ptr<T> {
+            type Output = Intersection<Self, U>;
+
+            fn intersection(self, rhs: U) -> Intersection<Self, U> {
+                Intersection(self, rhs)
+            }
+        }
+
+        impl<T: ?Sized, U> BitDifference<U> for $ptr<T> {
+            type Output = Difference<Self, U>;
+
+            fn difference(self, rhs: U) -> Difference<Self, U> {
+                Difference(self, rhs)
+            }
+        }
+
+        impl<T: ?Sized, U> BitSymmetricDifference<U> for $ptr<T> {
+            type Output = SymmetricDifference<Self, U>;
+
+            fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+                SymmetricDifference(self, rhs)
+            }
+        }
+
+        impl<T, U> BitUnionAssign<$ptr<U>> for $ptr<T>
+        where
+            T: BitUnionAssign<U> + BitSubset<U> + Clone,
+            U: BitSubset<T>,
+        {
+            fn union_assign(&mut self, rhs: &$ptr<U>) {
+                if !U::is_subset_of(&**rhs, &**self) {
+                    T::union_assign($ptr::make_mut(self), &**rhs)
+                }
+            }
+        }
+
+        impl<T, U> BitIntersectionAssign<$ptr<U>> for $ptr<T>
+        where
+            T: BitIntersectionAssign<U> + BitSubset<U> + Clone,
+        {
+            fn intersect_assign(&mut self, rhs: &$ptr<U>) {
+                if !T::is_subset_of(&**self, &**rhs) {
+                    T::intersect_assign($ptr::make_mut(self), &**rhs)
+                }
+            }
+        }
+
+        impl<T, U> BitDifferenceAssign<$ptr<U>> for $ptr<T>
+        where
+            T: BitDifferenceAssign<U> + BitDisjoint<U> + Clone,
+        {
+            fn difference_assign(&mut self, rhs: &$ptr<U>) {
+                if !T::is_disjoint(&**self, &**rhs) {
+                    T::difference_assign($ptr::make_mut(self), &**rhs)
+                }
+            }
+        }
+
+        impl<T, U> BitSymmetricDifferenceAssign<$ptr<U>> for $ptr<T>
+        where
+            T: BitSymmetricDifferenceAssign<U> + Clone,
+            U: BitTestNone,
+        {
+            fn symmetric_difference_assign(&mut self, rhs: &$ptr<U>) {
+                if !U::test_none(&**rhs) {
+                    T::symmetric_difference_assign($ptr::make_mut(self), &**rhs)
+                }
+            }
+        }
+
+        impl<T: ?Sized, U> BitSubset<U> for $ptr<T>
+        where
+            T: BitSubset<U>,
+        {
+            fn is_subset_of(&self, rhs: &U) -> bool {
+                T::is_subset_of(&**self, rhs)
+            }
+        }
+
+        impl<T: ?Sized, U> BitDisjoint<U> for $ptr<T>
+        where
+            T: BitDisjoint<U>,
+        {
+            fn is_disjoint(&self, rhs: &U) -> bool {
+                T::is_disjoint(&**self, rhs)
+            }
+        }
+
+        impl<T: ?Sized> PtrEq for $ptr<T> {
+            fn ptr_eq(&self, other: &Self) -> bool {
+                $ptr::ptr_eq(self, other)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_for_shared!(Rc);
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl_for_shared!(Arc);
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitEmpty for Cow<'_, B>
+where
+    B: ToOwned,
+    B::Owned: BitEmpty,
+{
+    fn empty() -> Self {
+        Cow::Owned(B::Owned::empty())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitFull for Cow<'_, B>
+where
+    B: ToOwned,
+    B::Owned: BitFull,
+{
+    fn full() -> Self {
+        Cow::Owned(B::Owned::full())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitTest for Cow<'_, B>
+where
+    B: ToOwned + BitTest,
+{
+    fn test(&self, idx: usize) -> bool {
+        B::test(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitTestNone for Cow<'_, B>
+where
+    B: ToOwned + BitTestNone,
+{
+    fn test_none(&self) -> bool {
+        B::test_none(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitTestAll for Cow<'_, B>
+where
+    B: ToOwned + BitTestAll,
+{
+    fn test_all(&self) -> bool {
+        B::test_all(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSetLimit for Cow<'_, B>
+where
+    B: ToOwned + BitSetLimit,
+{
+    const MAX_SET_INDEX: usize = B::MAX_SET_INDEX;
+}
+
+/// Owned bit-set may have different limit, so bits are set with bound checks.
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSet for Cow<'_, B>
+where
+    B: ToOwned + BitSetLimit + BitTest,
+    B::Owned: BitSet,
+{
+    unsafe fn set_unchecked(&mut self, idx: usize) {
+        if !B::test(self, idx) {
+            self.to_mut().set(idx)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitUnsetLimit for Cow<'_, B>
+where
+    B: ToOwned + BitUnsetLimit,
+{
+    const MAX_UNSET_INDEX: usize = B::MAX_UNSET_INDEX;
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitUnset for Cow<'_, B>
+where
+    B: ToOwned + BitUnsetLimit + BitTest,
+    B::Owned: BitUnset,
+{
+    unsafe fn unset_unchecked(&mut self, idx: usize) {
+        if B::test(self, idx) {
+            self.to_mut().unset(idx)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitTestRange for Cow<'_, B>
+where
+    B: ToOwned + BitTestRange,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        B::test_range_all(self, range)
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        B::test_range_any(self, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSetRange for Cow<'_, B>
+where
+    B: ToOwned + BitSetLimit + BitTest + BitTestRange,
+    B::Owned: BitSetRange,
+{
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        if !B::test_range_all(self, first..=last) {
+            self.to_mut().set_range(first..=last)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitUnsetRange for Cow<'_, B>
+where
+    B: ToOwned + BitUnsetLimit + BitTest + BitTestRange,
+    B::Owned: BitUnsetRange,
+{
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        if B::test_range_any(self, first..=last) {
+            self.to_mut().unset_range(first..=last)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSearch for Cow<'_, B>
+where
+    B: ToOwned + BitSearch,
+{
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        B::find_first_set(self, lower_bound)
+    }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        B::word64(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSearchRev for Cow<'_, B>
+where
+    B: ToOwned + BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        B::find_last_set(self, upper_bound)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSearchUnset for Cow<'_, B>
+where
+    B: ToOwned + BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        B::find_first_unset(self, lower_bound)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitSearchRun for Cow<'_, B>
+where
+    B: ToOwned + BitSearchRun,
+{
+    fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        B::find_first_run_set(self, len, lower_bound)
+    }
+
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        B::find_first_run_unset(self, len, lower_bound)
+    }
+
+    fn find_aligned_free(&self, order: u32) -> Option<usize> {
+        B::find_aligned_free(self, order)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitCount for Cow<'_, B>
+where
+    B: ToOwned + BitCount,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        B::count_in_range(self, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitRankSelect for Cow<'_, B>
+where
+    B: ToOwned + BitRankSelect,
+{
+    fn select(&self, n: usize) -> Option<usize> {
+        B::select(self, n)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized> BitComplement for Cow<'_, B>
+where
+    B: ToOwned,
+{
+    type Output = Complement<Self>;
+
+    fn complement(self) -> Complement<Self> {
+        Complement(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitUnion<U> for Cow<'_, B>
+where
+    B: ToOwned,
+{
+    type Output = Union<Self, U>;
+
+    fn union(self, rhs: U) -> Union<Self, U> {
+        Union(self, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitIntersection<U> for Cow<'_, B>
+where
+    B: ToOwned,
+{
+    type Output = Intersection<Self, U>;
+
+    fn intersection(self, rhs: U) -> Intersection<Self, U> {
+        Intersection(self, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitDifference<U> for Cow<'_, B>
+where
+    B: ToOwned,
+{
+    type Output = Difference<Self, U>;
+
+    fn difference(self, rhs: U) -> Difference<Self, U> {
+        Difference(self, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitSymmetricDifference<U> for Cow<'_, B>
+where
+    B: ToOwned,
+{
+    type Output = SymmetricDifference<Self, U>;
+
+    fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitUnionAssign<U> for Cow<'_, B>
+where
+    B: ToOwned,
+    B::Owned: BitUnionAssign<U>,
+{
+    fn union_assign(&mut self, rhs: &U) {
+        self.to_mut().union_assign(rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitIntersectionAssign<U> for Cow<'_, B>
+where
+    B: ToOwned,
+    B::Owned: BitIntersectionAssign<U>,
+{
+    fn intersect_assign(&mut self, rhs: &U) {
+        self.to_mut().intersect_assign(rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitDifferenceAssign<U> for Cow<'_, B>
+where
+    B: ToOwned,
+    B::Owned: BitDifferenceAssign<U>,
+{
+    fn difference_assign(&mut self, rhs: &U) {
+        self.to_mut().difference_assign(rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitSymmetricDifferenceAssign<U> for Cow<'_, B>
+where
+    B: ToOwned,
+    B::Owned: BitSymmetricDifferenceAssign<U>,
+{
+    fn symmetric_difference_assign(&mut self, rhs: &U) {
+        self.to_mut().symmetric_difference_assign(rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitSubset<U> for Cow<'_, B>
+where
+    B: ToOwned + BitSubset<U>,
+{
+    fn is_subset_of(&self, rhs: &U) -> bool {
+        B::is_subset_of(self, rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized, U> BitDisjoint<U> for Cow<'_, B>
+where
+    B: ToOwned + BitDisjoint<U>,
+{
+    fn is_disjoint(&self, rhs: &U) -> bool {
+        B::is_disjoint(self, rhs)
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::{ops::*, union::Union},
+    crate::{iter::SetBits, ops::*, symmetric_difference::SymmetricDifference, union::Union},
     core::fmt::{self, Display},
 };
 
@@ -116,6 +116,31 @@ where
     }
 }
 
+impl<T, U> BitTestRange for Intersection<T, U>
+where
+    Intersection<T, U>: BitSearch + BitSearchUnset,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::range_bounds(&range, usize::MAX) {
+            None => true,
+            Some((first, last)) => match self.find_first_unset(first) {
+                None => true,
+                Some(idx) => idx > last,
+            },
+        }
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.find_set_in_range(range).is_some()
+    }
+}
+
 impl<T, U> BitSearch for Intersection<T, U>
 where
     T: BitSearch,
@@ -135,6 +160,76 @@ where
             }
         }
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        Some(self.0.word64(idx)? & self.1.word64(idx)?)
+    }
+}
+
+impl<T, U> BitSearchUnset for Intersection<T, U>
+where
+    T: BitSearchUnset,
+    U: BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        let t = self.0.find_first_unset(lower_bound);
+        let u = self.1.find_first_unset(lower_bound);
+
+        match (t, u) {
+            (None, None) => None,
+            (Some(t), None) => Some(t),
+            (None, Some(u)) => Some(u),
+            (Some(t), Some(u)) => Some(t.min(u)),
+        }
+    }
+}
+
+impl<T, U> BitSearchRun for Intersection<T, U> where Self: BitSearch + BitSearchUnset + BitSetLimit {}
+
+impl<T, U> BitCount for Intersection<T, U>
+where
+    T: BitSearch,
+    U: BitSearch,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        crate::count_intersection(&self.0, &self.1, range)
+    }
+}
+
+impl<T, U> BitSearchRev for Intersection<T, U>
+where
+    T: BitSearchRev,
+    U: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        let mut t = self.0.find_last_set(upper_bound)?;
+        let mut u = self.1.find_last_set(upper_bound)?;
+
+        loop {
+            if t == u {
+                return Some(t);
+            } else if t > u {
+                t = self.0.find_last_set(t - 1)?;
+            } else {
+                u = self.1.find_last_set(u - 1)?;
+            }
+        }
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a Intersection<T, U>
+where
+    Intersection<T, U>: BitSearch + BitSetLimit,
+{
+    type Item = usize;
+    type IntoIter = SetBits<&'a Intersection<T, U>>;
+
+    fn into_iter(self) -> SetBits<&'a Intersection<T, U>> {
+        SetBits::new(self)
+    }
 }
 
 impl<T, U> BitComplement for Intersection<T, U>
@@ -183,3 +278,14 @@ where
         Intersection(self.0.difference(rhs), self.1)
     }
 }
+
+impl<T, U, Y> BitSymmetricDifference<Y> for Intersection<T, U> {
+    type Output = SymmetricDifference<Intersection<T, U>, Y>;
+
+    fn symmetric_difference(self, rhs: Y) -> Self::Output {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl_bit_operators!(impl[T, U] for Intersection<T, U>);
+impl_bit_operators!(impl['a, T, U] for &'a Intersection<T, U>);
@@ -0,0 +1,198 @@
+use {
+    crate::{iter::SetBits, ops::*},
+    core::ops::Deref,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Bit-set wrapper that acts like intersection of any number of bit-sets.
+///
+/// Wraps slice-like collection of bit-sets, e.g. `&[S]` or `Vec<S>`.
+/// Intersection of empty collection has all bits set up to `S::MAX_SET_INDEX`.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let sets = [0b1011u8, 0b0111, 0b1110_0011];
+/// let intersection = IntersectionAll(&sets[..]);
+///
+/// assert!(intersection.test(1) && !intersection.test(2));
+/// assert!(intersection.iter_set().eq([0, 1]));
+/// assert_eq!(intersection.fold::<u8>(), 0b0011);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntersectionAll<C>(pub C);
+
+impl<C, S> IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+{
+    /// Returns bit-sets in the intersection.
+    pub fn sets(&self) -> &[S] {
+        &self.0
+    }
+
+    /// Evaluates intersection into concrete bit-set, merging whole bit-sets at once.
+    pub fn fold<T>(&self) -> T
+    where
+        T: BitFull + BitIntersectionAssign<S>,
+    {
+        let mut result = T::full();
+        for set in self.sets() {
+            result.intersect_assign(set);
+        }
+        result
+    }
+}
+
+impl<C, S> BitTest for IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitTest + BitSetLimit,
+{
+    fn test(&self, idx: usize) -> bool {
+        idx <= S::MAX_SET_INDEX && self.sets().iter().all(|set| set.test(idx))
+    }
+}
+
+impl<C, S> BitTestNone for IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch + BitSetLimit,
+{
+    fn test_none(&self) -> bool {
+        self.find_first_set(0).is_none()
+    }
+}
+
+impl<C, S> BitSetLimit for IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSetLimit,
+{
+    const MAX_SET_INDEX: usize = S::MAX_SET_INDEX;
+}
+
+impl<C, S> BitSearch for IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch + BitSetLimit,
+{
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        let sets = self.sets();
+        if sets.is_empty() {
+            return if lower_bound <= S::MAX_SET_INDEX {
+                Some(lower_bound)
+            } else {
+                None
+            };
+        }
+
+        // Leapfrog. Lagging bit-set is advanced to the candidate
+        // until all bit-sets agree on it.
+        let mut candidate = lower_bound;
+        let mut agreed = 0;
+        let mut member = 0;
+
+        while agreed < sets.len() {
+            let idx = sets[member].find_first_set(candidate)?;
+            if idx == candidate {
+                agreed += 1;
+            } else {
+                candidate = idx;
+                agreed = 1;
+            }
+            member = (member + 1) % sets.len();
+        }
+
+        Some(candidate)
+    }
+}
+
+impl<C, S> BitCount for IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch + BitSetLimit,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        if self.sets().is_empty() {
+            // All bits up to the limit are set.
+            return match crate::inclusive_bounds(&range, S::MAX_SET_INDEX) {
+                None => 0,
+                Some((start, end)) => (end - start).saturating_add(1),
+            };
+        }
+
+        crate::count_by_search(self, range)
+    }
+}
+
+impl<'a, C, S> IntoIterator for &'a IntersectionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch + BitSetLimit,
+{
+    type Item = usize;
+    type IntoIter = SetBits<&'a IntersectionAll<C>>;
+
+    fn into_iter(self) -> SetBits<&'a IntersectionAll<C>> {
+        SetBits::new(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> core::iter::FromIterator<S> for IntersectionAll<Vec<S>> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        IntersectionAll(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{test_util::Rng, Bits4096},
+    };
+
+    #[test]
+    fn matches_model() {
+        let mut rng = Rng::new(31);
+        for _ in 0..30 {
+            let mut sets = [Bits4096::empty(); 6];
+            let members = rng.below(sets.len() + 1);
+            for set in &mut sets[..members] {
+                // Dense sets so that intersection is not empty.
+                for _ in 0..3000 {
+                    set.set(rng.below(4096));
+                }
+            }
+
+            let intersection = IntersectionAll(&sets[..members]);
+            let model: Vec<bool> = (0..4096)
+                .map(|i| sets[..members].iter().all(|set| set.test(i)))
+                .collect();
+            let expected = (0..4096).filter(|&i| model[i]);
+            assert!(intersection.iter_set().eq(expected.clone()));
+            assert_eq!(intersection.count_ones(), expected.clone().count());
+            assert_eq!(intersection.test_none(), expected.clone().next().is_none());
+            assert!(intersection
+                .fold::<Bits4096>()
+                .iter_set()
+                .eq(expected.clone()));
+
+            for _ in 0..20 {
+                let lower_bound = rng.below(4100);
+                let expected = (lower_bound..4096).find(|&i| model[i]);
+                assert_eq!(intersection.find_first_set(lower_bound), expected);
+            }
+        }
+    }
+}
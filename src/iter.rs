@@ -0,0 +1,185 @@
+use {
+    crate::ops::*,
+    core::{iter::FusedIterator, ops::RangeBounds},
+};
+
+/// Iterator over indices of bits set in the bit-set.
+///
+/// Yields indices in ascending order and can be reversed
+/// if underlying bit-set supports reverse search.
+/// Bits are not counted, so size hint is bounded only by the remaining range.
+/// Use `BitCount` to get exact number of bits set.
+#[derive(Clone, Debug)]
+pub struct SetBits<S> {
+    set: S,
+    front: usize,
+    back: usize,
+    done: bool,
+}
+
+impl<S> SetBits<S>
+where
+    S: BitSetLimit,
+{
+    /// Returns iterator over all bits set in the bit-set.
+    pub fn new(set: S) -> Self {
+        SetBits::with_range(set, ..)
+    }
+
+    /// Returns iterator over bits set in the bit-set within specified range.
+    pub fn with_range<R>(set: S, range: R) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        match crate::inclusive_bounds(&range, S::MAX_SET_INDEX) {
+            None => SetBits {
+                set,
+                front: 0,
+                back: 0,
+                done: true,
+            },
+            Some((front, back)) => SetBits {
+                set,
+                front,
+                back,
+                done: false,
+            },
+        }
+    }
+}
+
+impl<S> SetBits<S> {
+    /// Returns underlying bit-set.
+    pub fn into_inner(self) -> S {
+        self.set
+    }
+}
+
+impl<S> Iterator for SetBits<S>
+where
+    S: BitSearch,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+
+        match self.set.find_first_set(self.front) {
+            Some(idx) if idx <= self.back => {
+                if idx == self.back {
+                    self.done = true;
+                } else {
+                    self.front = idx + 1;
+                }
+                Some(idx)
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, (self.back - self.front).checked_add(1))
+        }
+    }
+}
+
+impl<S> DoubleEndedIterator for SetBits<S>
+where
+    S: BitSearch + BitSearchRev,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        if self.done {
+            return None;
+        }
+
+        match self.set.find_last_set(self.back) {
+            Some(idx) if idx >= self.front => {
+                if idx == self.front {
+                    self.done = true;
+                } else {
+                    self.back = idx - 1;
+                }
+                Some(idx)
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<S> FusedIterator for SetBits<S> where S: BitSearch {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{test_util::Rng, Bits4096, Difference, Union},
+    };
+
+    fn random_set(rng: &mut Rng) -> (Bits4096, [bool; 4096]) {
+        let mut set = Bits4096::empty();
+        let mut model = [false; 4096];
+        let density = 1 + rng.below(64);
+        for _ in 0..density {
+            let first = rng.below(4096);
+            let last = (first + rng.below(100)).min(4096);
+            set.set_range(first..last);
+            model[first..last].fill(true);
+        }
+        (set, model)
+    }
+
+    #[test]
+    fn both_ends_match_model() {
+        let mut rng = Rng::new(1);
+        for _ in 0..50 {
+            let (lhs, lhs_model) = random_set(&mut rng);
+            let (rhs, rhs_model) = random_set(&mut rng);
+            let union = Union(&lhs, &rhs);
+            let difference = Difference(&lhs, &rhs);
+
+            let mut expected: Vec<usize> = (0..4096).filter(|&i| lhs_model[i]).collect();
+            let mut iter = lhs.iter_set();
+            let mut layered = lhs.iter();
+            while !expected.is_empty() {
+                let (lower, upper) = iter.size_hint();
+                assert!(
+                    lower <= expected.len()
+                        && !matches!(upper, Some(upper) if upper < expected.len())
+                );
+                assert_eq!(layered.len(), expected.len());
+
+                if rng.below(2) == 0 {
+                    let idx = expected.remove(0);
+                    assert_eq!(iter.next(), Some(idx));
+                    assert_eq!(layered.next(), Some(idx));
+                } else {
+                    let idx = expected.pop();
+                    assert_eq!(iter.next_back(), idx);
+                    assert_eq!(layered.next_back(), idx);
+                }
+            }
+            assert_eq!((iter.next(), iter.next_back()), (None, None));
+            assert_eq!((layered.next(), layered.next_back()), (None, None));
+            assert_eq!(layered.size_hint(), (0, Some(0)));
+
+            let union_model = (0..4096).filter(|&i| lhs_model[i] || rhs_model[i]);
+            assert!(union.iter_set().rev().eq(union_model.rev()));
+            let difference_model = (0..4096).filter(|&i| lhs_model[i] && !rhs_model[i]);
+            assert!((&difference).into_iter().eq(difference_model));
+        }
+    }
+}
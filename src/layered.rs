@@ -1,14 +1,71 @@
-use crate::{
-    complement::Complement, difference::Difference, intersection::Intersection, ops::*,
-    union::Union,
+use {
+    crate::{
+        complement::Complement, difference::Difference, intersection::Intersection, iter::SetBits,
+        ops::*, symmetric_difference::SymmetricDifference, union::Union,
+    },
+    core::iter::FusedIterator,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
 #[derive(Clone, Copy, Debug)]
 pub struct Layered<T, B, const N: usize> {
     top: T,
     bottom: [B; N],
 }
 
+impl<T, B, const N: usize> Layered<T, B, N> {
+    /// Assembles bit-set from layers.
+    /// Top layer must have bits set exactly for non-empty bottom layers.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub(crate) fn from_layers(top: T, bottom: [B; N]) -> Self {
+        Layered { top, bottom }
+    }
+}
+
+impl<T, B, const N: usize> Layered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitSearch + BitCount + BitSetLimit,
+{
+    /// Returns iterator over indices of bits set.
+    ///
+    /// Unlike iterating with `BitIter::iter_set` this iterator keeps a cursor
+    /// into top layer and does not re-descend from the top on each step.
+    /// Bits set are counted once here, so the iterator knows its exact length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits4096::empty();
+    /// set.set(3);
+    /// set.set(64);
+    /// set.set(4095);
+    ///
+    /// let mut iter = set.iter();
+    /// assert_eq!(iter.len(), 3);
+    /// iter.next_back();
+    /// assert_eq!(iter.size_hint(), (2, Some(2)));
+    /// ```
+    pub fn iter(&self) -> LayeredIter<'_, T, B, N> {
+        let top = SetBits::with_range(&self.top, ..N);
+        let remaining = top.clone().map(|t| self.bottom[t].count_ones()).sum();
+
+        LayeredIter {
+            top,
+            bottom: &self.bottom,
+            front: None,
+            back: None,
+            remaining,
+        }
+    }
+}
+
 impl<T, B, const N: usize> BitEmpty for Layered<T, B, N>
 where
     T: BitEmpty,
@@ -22,30 +79,66 @@ where
     }
 }
 
+impl<T, B, const N: usize> BitFull for Layered<T, B, N>
+where
+    T: BitEmpty + BitSetRange,
+    B: BitEmpty + BitSetRange,
+{
+    fn full() -> Self {
+        let mut set = Self::empty();
+        unsafe {
+            // # Safe
+            // Range is within bounds.
+            set.set_range_unchecked(0, Self::MAX_SET_INDEX);
+        }
+        set
+    }
+}
+
 impl<T, B, const N: usize> BitTest for Layered<T, B, N>
 where
     T: BitTest + BitSetLimit,
     B: BitTest + BitSetLimit,
 {
     fn test(&self, idx: usize) -> bool {
-        if idx >= Self::MAX_SET_INDEX {
+        if idx > Self::MAX_SET_INDEX {
             false
         } else {
             let t = idx / (B::MAX_SET_INDEX + 1);
             let b = idx % (B::MAX_SET_INDEX + 1);
 
-            self.bottom[t as usize].test(b)
+            self.bottom[t].test(b)
         }
     }
 }
 
+impl<T, B, const N: usize> BitTestNone for Layered<T, B, N>
+where
+    T: BitTestNone,
+{
+    fn test_none(&self) -> bool {
+        // Top layer has bits set only for non-empty bottom layers.
+        self.top.test_none()
+    }
+}
+
+impl<T, B, const N: usize> BitTestAll for Layered<T, B, N>
+where
+    T: BitTest + BitSearch + BitSetLimit,
+    B: BitTestRange + BitSetLimit,
+{
+    fn test_all(&self) -> bool {
+        self.test_range_all(..=Self::MAX_SET_INDEX)
+    }
+}
+
 impl<T, B, const N: usize> BitSearch for Layered<T, B, N>
 where
     T: BitSearch + BitSetLimit,
     B: BitSearch + BitSetLimit,
 {
     fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
-        if lower_bound >= Self::MAX_SET_INDEX {
+        if lower_bound > Self::MAX_SET_INDEX {
             None
         } else {
             let t = lower_bound / (B::MAX_SET_INDEX + 1);
@@ -53,22 +146,225 @@ where
 
             if b == 0 {
                 let t = self.top.find_first_set(t)?;
-                let b = self.bottom[t as usize].find_first_set(0)?;
+                let b = self.bottom[t].find_first_set(0)?;
                 Some(t * (B::MAX_SET_INDEX + 1) + b)
             } else {
                 let mut t_set = self.top.find_first_set(t)?;
                 if t == t_set {
-                    if let Some(b) = self.bottom[t as usize].find_first_set(b) {
+                    if let Some(b) = self.bottom[t].find_first_set(b) {
                         return Some(t * (B::MAX_SET_INDEX + 1) + b);
                     }
                     t_set = self.top.find_first_set(t + 1)?;
                 }
 
-                let b = self.bottom[t_set as usize].find_first_set(0)?;
+                let b = self.bottom[t_set].find_first_set(0)?;
                 Some(t_set * (B::MAX_SET_INDEX + 1) + b)
             }
         }
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        // Words are taken from bottom layers, so they must not straddle them.
+        if (B::MAX_SET_INDEX + 1) % 64 != 0 {
+            return None;
+        }
+
+        let words = (B::MAX_SET_INDEX + 1) / 64;
+        match self.bottom.get(idx / words) {
+            None => Some(0),
+            Some(bottom) => bottom.word64(idx % words),
+        }
+    }
+}
+
+impl<T, B, const N: usize> BitCount for Layered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitCount + BitSetLimit,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (start, end) = match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+            None => return 0,
+            Some(bounds) => bounds,
+        };
+
+        let t_start = start / (B::MAX_SET_INDEX + 1);
+        let t_end = end / (B::MAX_SET_INDEX + 1);
+
+        let mut count = 0;
+        let mut t = t_start;
+
+        // Empty bottom layers are skipped using top layer.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set > t_end {
+                break;
+            }
+
+            let lower = if t_set == t_start {
+                start % (B::MAX_SET_INDEX + 1)
+            } else {
+                0
+            };
+
+            let upper = if t_set == t_end {
+                end % (B::MAX_SET_INDEX + 1)
+            } else {
+                B::MAX_SET_INDEX
+            };
+
+            count += self.bottom[t_set].count_in_range(lower..=upper);
+            t = t_set + 1;
+        }
+
+        count
+    }
+}
+
+impl<T, B, const N: usize> BitRankSelect for Layered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitRankSelect + BitSetLimit,
+{
+    fn select(&self, mut n: usize) -> Option<usize> {
+        let mut t = 0;
+
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            let count = self.bottom[t_set].count_ones();
+            if n < count {
+                let b = self.bottom[t_set].select(n)?;
+                return Some(t_set * (B::MAX_SET_INDEX + 1) + b);
+            }
+
+            n -= count;
+            t = t_set + 1;
+        }
+
+        None
+    }
+}
+
+impl<T, B, const N: usize> BitSearchUnset for Layered<T, B, N>
+where
+    T: BitTest + BitSetLimit,
+    B: BitSearchUnset + BitSetLimit,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        if lower_bound > Self::MAX_SET_INDEX {
+            return Some(lower_bound);
+        }
+
+        let mut t = lower_bound / (B::MAX_SET_INDEX + 1);
+        let mut b = lower_bound % (B::MAX_SET_INDEX + 1);
+
+        loop {
+            if !self.top.test(t) {
+                // Bottom layer is empty.
+                return Some(t * (B::MAX_SET_INDEX + 1) + b);
+            }
+
+            if let Some(b) = self.bottom[t].find_first_unset(b) {
+                if b <= B::MAX_SET_INDEX {
+                    return Some(t * (B::MAX_SET_INDEX + 1) + b);
+                }
+            }
+
+            t += 1;
+            b = 0;
+
+            if t > crate::min(T::MAX_SET_INDEX, N - 1) {
+                return Some(Self::MAX_SET_INDEX + 1);
+            }
+        }
+    }
+}
+
+impl<T, B, const N: usize> BitSearchRun for Layered<T, B, N>
+where
+    T: BitTest + BitSearch + BitSetLimit,
+    B: BitSearchRun + BitSearchRev,
+{
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        if len == 0 {
+            return Some(lower_bound).filter(|&idx| idx <= Self::MAX_SET_INDEX);
+        }
+        if lower_bound > Self::MAX_SET_INDEX || len - 1 > Self::MAX_SET_INDEX - lower_bound {
+            return None;
+        }
+
+        let size = B::MAX_SET_INDEX + 1;
+        let first = lower_bound / size;
+
+        // Number of bits unset at the end of previous bottom layers.
+        let mut carry = 0;
+
+        for t in first..=crate::min(T::MAX_SET_INDEX, N - 1) {
+            let lower = if t == first { lower_bound % size } else { 0 };
+
+            if !self.top.test(t) {
+                // Bottom layer is empty, count it whole without visiting.
+                carry += size - lower;
+                if carry >= len {
+                    return Some((t + 1) * size - carry);
+                }
+                continue;
+            }
+
+            let bottom = &self.bottom[t];
+            let prefix_end = bottom.find_first_set(lower).unwrap_or(size);
+            if carry + prefix_end - lower >= len {
+                return Some(t * size + lower - carry);
+            }
+            if prefix_end == size {
+                carry += size - lower;
+                continue;
+            }
+
+            if let Some(b) = bottom.find_first_run_unset(len, prefix_end) {
+                return Some(t * size + b);
+            }
+
+            carry = match bottom.find_last_set(B::MAX_SET_INDEX) {
+                Some(last) => B::MAX_SET_INDEX - last,
+                None => size,
+            };
+        }
+
+        None
+    }
+}
+
+impl<T, B, const N: usize> BitSearchRev for Layered<T, B, N>
+where
+    T: BitSearchRev + BitSetLimit,
+    B: BitSearchRev + BitSetLimit,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        let upper_bound = upper_bound.min(Self::MAX_SET_INDEX);
+
+        let t = upper_bound / (B::MAX_SET_INDEX + 1);
+        let b = upper_bound % (B::MAX_SET_INDEX + 1);
+
+        let mut t_set = self.top.find_last_set(t)?;
+        if t == t_set {
+            if let Some(b) = self.bottom[t].find_last_set(b) {
+                return Some(t * (B::MAX_SET_INDEX + 1) + b);
+            }
+            if t == 0 {
+                return None;
+            }
+            t_set = self.top.find_last_set(t - 1)?;
+        }
+
+        let b = self.bottom[t_set].find_last_set(B::MAX_SET_INDEX)?;
+        Some(t_set * (B::MAX_SET_INDEX + 1) + b)
+    }
 }
 
 impl<T, B, const N: usize> BitSetLimit for Layered<T, B, N>
@@ -77,7 +373,7 @@ where
     B: BitSetLimit,
 {
     const MAX_SET_INDEX: usize =
-        crate::max(T::MAX_SET_INDEX, N - 1) * (B::MAX_SET_INDEX + 1) + B::MAX_SET_INDEX;
+        crate::min(T::MAX_SET_INDEX, N - 1) * (B::MAX_SET_INDEX + 1) + B::MAX_SET_INDEX;
 }
 
 impl<T, B, const N: usize> BitSet for Layered<T, B, N>
@@ -90,7 +386,7 @@ where
         let u = idx % (B::MAX_SET_INDEX + 1);
 
         self.top.set_unchecked(t);
-        self.bottom[t as usize].set_unchecked(u)
+        self.bottom[t].set_unchecked(u)
     }
 }
 
@@ -100,7 +396,7 @@ impl<T, B, const N: usize> BitUnsetLimit for Layered<T, B, N> {
 
 impl<T, B, const N: usize> BitUnset for Layered<T, B, N>
 where
-    T: BitUnset,
+    T: BitUnset + BitSetLimit,
     B: BitUnset + BitSetLimit + BitTestNone,
 {
     unsafe fn unset_unchecked(&mut self, idx: usize) {
@@ -108,16 +404,175 @@ where
             panic!("This kind of layered bitset cannot support bit unsetting");
         }
 
+        if idx > Self::MAX_SET_INDEX {
+            return;
+        }
+
         let t = idx / (B::MAX_SET_INDEX + 1);
         let u = idx % (B::MAX_SET_INDEX + 1);
 
-        self.bottom[t as usize].unset_unchecked(u);
-        if !self.bottom[t as usize].test_none() {
+        self.bottom[t].unset_unchecked(u);
+        if self.bottom[t].test_none() {
             self.top.unset_unchecked(t);
         }
     }
 }
 
+impl<T, B, const N: usize> BitSetRange for Layered<T, B, N>
+where
+    T: BitSetRange,
+    B: BitSetRange,
+{
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        let t_first = first / (B::MAX_SET_INDEX + 1);
+        let t_last = last / (B::MAX_SET_INDEX + 1);
+
+        for t in t_first..=t_last {
+            let lower = if t == t_first {
+                first % (B::MAX_SET_INDEX + 1)
+            } else {
+                0
+            };
+
+            let upper = if t == t_last {
+                last % (B::MAX_SET_INDEX + 1)
+            } else {
+                B::MAX_SET_INDEX
+            };
+
+            self.bottom[t].set_range_unchecked(lower, upper);
+        }
+
+        self.top.set_range_unchecked(t_first, t_last);
+    }
+}
+
+impl<T, B, const N: usize> BitUnsetRange for Layered<T, B, N>
+where
+    T: BitUnset + BitSearch + BitSetLimit,
+    B: BitUnsetRange + BitSetLimit + BitTestNone,
+{
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        if T::MAX_UNSET_INDEX < N || B::MAX_UNSET_INDEX < B::MAX_SET_INDEX {
+            panic!("This kind of layered bitset cannot support bit unsetting");
+        }
+
+        if first > Self::MAX_SET_INDEX {
+            return;
+        }
+
+        let last = last.min(Self::MAX_SET_INDEX);
+        let t_first = first / (B::MAX_SET_INDEX + 1);
+        let t_last = last / (B::MAX_SET_INDEX + 1);
+
+        let mut t = t_first;
+
+        // Empty bottom layers are skipped using top layer.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set > t_last {
+                break;
+            }
+
+            let lower = if t_set == t_first {
+                first % (B::MAX_SET_INDEX + 1)
+            } else {
+                0
+            };
+
+            let upper = if t_set == t_last {
+                last % (B::MAX_SET_INDEX + 1)
+            } else {
+                B::MAX_SET_INDEX
+            };
+
+            self.bottom[t_set].unset_range_unchecked(lower, upper);
+            if self.bottom[t_set].test_none() {
+                self.top.unset_unchecked(t_set);
+            }
+
+            t = t_set + 1;
+        }
+    }
+}
+
+impl<T, B, const N: usize> BitTestRange for Layered<T, B, N>
+where
+    T: BitTest + BitSearch + BitSetLimit,
+    B: BitTestRange + BitSetLimit,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (first, last) = match crate::range_bounds(&range, usize::MAX) {
+            None => return true,
+            Some((_, last)) if last > Self::MAX_SET_INDEX => return false,
+            Some(bounds) => bounds,
+        };
+
+        let t_first = first / (B::MAX_SET_INDEX + 1);
+        let t_last = last / (B::MAX_SET_INDEX + 1);
+
+        (t_first..=t_last).all(|t| {
+            let lower = if t == t_first {
+                first % (B::MAX_SET_INDEX + 1)
+            } else {
+                0
+            };
+
+            let upper = if t == t_last {
+                last % (B::MAX_SET_INDEX + 1)
+            } else {
+                B::MAX_SET_INDEX
+            };
+
+            self.top.test(t) && self.bottom[t].test_range_all(lower..=upper)
+        })
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (first, last) = match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+            None => return false,
+            Some(bounds) => bounds,
+        };
+
+        let t_first = first / (B::MAX_SET_INDEX + 1);
+        let t_last = last / (B::MAX_SET_INDEX + 1);
+
+        let mut t = t_first;
+
+        // Empty bottom layers are skipped using top layer.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set > t_last {
+                break;
+            }
+
+            let lower = if t_set == t_first {
+                first % (B::MAX_SET_INDEX + 1)
+            } else {
+                0
+            };
+
+            let upper = if t_set == t_last {
+                last % (B::MAX_SET_INDEX + 1)
+            } else {
+                B::MAX_SET_INDEX
+            };
+
+            if self.bottom[t_set].test_range_any(lower..=upper) {
+                return true;
+            }
+
+            t = t_set + 1;
+        }
+
+        false
+    }
+}
+
 impl<T, B, const N: usize> BitComplement for Layered<T, B, N> {
     type Output = Complement<Self>;
 
@@ -149,3 +604,718 @@ impl<T, B, U, const N: usize> BitDifference<U> for Layered<T, B, N> {
         Difference(self, rhs)
     }
 }
+
+impl<T, B, U, const N: usize> BitSymmetricDifference<U> for Layered<T, B, N> {
+    type Output = SymmetricDifference<Self, U>;
+
+    fn symmetric_difference(self, rhs: U) -> SymmetricDifference<Self, U> {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl_bit_operators!(impl[T, B, const N: usize] for Layered<T, B, N>);
+impl_bit_operators!(impl['a, T, B, const N: usize] for &'a Layered<T, B, N>);
+
+impl<T, B, const N: usize> Layered<T, B, N>
+where
+    T: BitTest + BitSetLimit,
+    B: BitRankSelect + BitSetLimit,
+{
+    /// Builds directory of cumulative bit counts for bottom layers.
+    ///
+    /// Directory answers rank queries in constant number of bottom layer queries
+    /// and select queries with binary search over bottom layers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits65536::empty();
+    /// set.set(10);
+    /// set.set(5000);
+    /// set.set(60000);
+    ///
+    /// let directory = set.rank_directory();
+    /// assert_eq!(directory.rank(5000), 1);
+    /// assert_eq!(directory.select(2), Some(60000));
+    /// assert_eq!(directory.select(3), None);
+    /// ```
+    pub fn rank_directory(&self) -> RankDirectory<'_, T, B, N> {
+        let mut prefix = [0; N];
+        let mut total = 0;
+
+        for (t, count) in prefix.iter_mut().enumerate() {
+            *count = total;
+            if self.top.test(t) {
+                total += self.bottom[t].count_ones();
+            }
+        }
+
+        RankDirectory {
+            set: self,
+            prefix,
+            total,
+        }
+    }
+}
+
+/// Directory of cumulative bit counts for bottom layers of `Layered` bit-set.
+///
+/// Borrows the bit-set, so it cannot become stale.
+#[derive(Clone, Debug)]
+pub struct RankDirectory<'a, T, B, const N: usize> {
+    set: &'a Layered<T, B, N>,
+    prefix: [usize; N],
+    total: usize,
+}
+
+impl<T, B, const N: usize> RankDirectory<'_, T, B, N>
+where
+    T: BitTest + BitSetLimit,
+    B: BitRankSelect + BitSetLimit,
+{
+    /// Returns number of bits set with index less than `idx`.
+    pub fn rank(&self, idx: usize) -> usize {
+        if idx > Layered::<T, B, N>::MAX_SET_INDEX {
+            return self.total;
+        }
+
+        let t = idx / (B::MAX_SET_INDEX + 1);
+        let b = idx % (B::MAX_SET_INDEX + 1);
+
+        if self.set.top.test(t) {
+            self.prefix[t] + self.set.bottom[t].rank(b)
+        } else {
+            self.prefix[t]
+        }
+    }
+
+    /// Returns index of `n`-th bit set, counting from zero.
+    pub fn select(&self, n: usize) -> Option<usize> {
+        if n >= self.total {
+            return None;
+        }
+
+        // Last bottom layer that starts at or before `n`-th bit set.
+        let t = self.prefix.partition_point(|&count| count <= n) - 1;
+        let b = self.set.bottom[t].select(n - self.prefix[t])?;
+        Some(t * (B::MAX_SET_INDEX + 1) + b)
+    }
+
+    /// Returns total number of bits set.
+    pub fn count_ones(&self) -> usize {
+        self.total
+    }
+}
+
+impl<T, B, TR, BR, const N: usize> BitUnionAssign<Layered<TR, BR, N>> for Layered<T, B, N>
+where
+    T: BitSet,
+    B: BitUnionAssign<BR> + BitSetLimit,
+    TR: BitSearch,
+    BR: BitSetLimit,
+{
+    fn union_assign(&mut self, rhs: &Layered<TR, BR, N>) {
+        assert_eq!(
+            B::MAX_SET_INDEX,
+            BR::MAX_SET_INDEX,
+            "Bottom layers must be of the same size"
+        );
+
+        let mut t = 0;
+        while let Some(t_set) = rhs.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            self.bottom[t_set].union_assign(&rhs.bottom[t_set]);
+            unsafe {
+                // # Safe
+                // `t_set < N` and top layer of `rhs` can hold it.
+                self.top.set_unchecked(t_set);
+            }
+            t = t_set + 1;
+        }
+    }
+}
+
+impl<T, B, TR, BR, const N: usize> BitIntersectionAssign<Layered<TR, BR, N>> for Layered<T, B, N>
+where
+    T: BitSearch + BitUnset,
+    B: BitIntersectionAssign<BR> + BitEmpty + BitTestNone + BitSetLimit,
+    TR: BitTest,
+    BR: BitSetLimit,
+{
+    fn intersect_assign(&mut self, rhs: &Layered<TR, BR, N>) {
+        assert_eq!(
+            B::MAX_SET_INDEX,
+            BR::MAX_SET_INDEX,
+            "Bottom layers must be of the same size"
+        );
+
+        let mut t = 0;
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            if rhs.top.test(t_set) {
+                self.bottom[t_set].intersect_assign(&rhs.bottom[t_set]);
+            } else {
+                self.bottom[t_set] = B::empty();
+            }
+
+            if self.bottom[t_set].test_none() {
+                self.top.unset(t_set);
+            }
+            t = t_set + 1;
+        }
+    }
+}
+
+impl<T, B, TR, BR, const N: usize> BitDifferenceAssign<Layered<TR, BR, N>> for Layered<T, B, N>
+where
+    T: BitSearch + BitUnset,
+    B: BitDifferenceAssign<BR> + BitTestNone + BitSetLimit,
+    TR: BitTest,
+    BR: BitSetLimit,
+{
+    fn difference_assign(&mut self, rhs: &Layered<TR, BR, N>) {
+        assert_eq!(
+            B::MAX_SET_INDEX,
+            BR::MAX_SET_INDEX,
+            "Bottom layers must be of the same size"
+        );
+
+        let mut t = 0;
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            if rhs.top.test(t_set) {
+                self.bottom[t_set].difference_assign(&rhs.bottom[t_set]);
+                if self.bottom[t_set].test_none() {
+                    self.top.unset(t_set);
+                }
+            }
+            t = t_set + 1;
+        }
+    }
+}
+
+impl<T, B, TR, BR, const N: usize> BitSymmetricDifferenceAssign<Layered<TR, BR, N>>
+    for Layered<T, B, N>
+where
+    T: BitSet + BitUnset,
+    B: BitSymmetricDifferenceAssign<BR> + BitTestNone + BitSetLimit,
+    TR: BitSearch,
+    BR: BitSetLimit,
+{
+    fn symmetric_difference_assign(&mut self, rhs: &Layered<TR, BR, N>) {
+        assert_eq!(
+            B::MAX_SET_INDEX,
+            BR::MAX_SET_INDEX,
+            "Bottom layers must be of the same size"
+        );
+
+        let mut t = 0;
+        while let Some(t_set) = rhs.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            // Bottom layer may become empty or non-empty, top layer bit follows.
+            self.bottom[t_set].symmetric_difference_assign(&rhs.bottom[t_set]);
+            if self.bottom[t_set].test_none() {
+                self.top.unset(t_set);
+            } else {
+                unsafe {
+                    // # Safe
+                    // `t_set < N` and top layer of `rhs` can hold it.
+                    self.top.set_unchecked(t_set);
+                }
+            }
+            t = t_set + 1;
+        }
+    }
+}
+
+impl_assign_by_search!(impl[T, B, const N: usize] for Layered<T, B, N>; Union Intersection Difference SymmetricDifference);
+
+impl<T, B, X, const N: usize> BitUnionAssign<Complement<X>> for Layered<T, B, N>
+where
+    Self: BitSet,
+    Complement<X>: BitSearch,
+{
+    fn union_assign(&mut self, rhs: &Complement<X>) {
+        crate::union_assign_by_search(self, rhs)
+    }
+}
+
+impl<T, B, X, const N: usize> BitIntersectionAssign<Complement<X>> for Layered<T, B, N>
+where
+    Self: BitSearch + BitUnset,
+    X: BitTest,
+{
+    fn intersect_assign(&mut self, rhs: &Complement<X>) {
+        crate::intersect_assign_by_search(self, rhs)
+    }
+}
+
+impl<T, B, X, const N: usize> BitDifferenceAssign<Complement<X>> for Layered<T, B, N>
+where
+    Self: BitSearch + BitUnset,
+    X: BitTest,
+{
+    fn difference_assign(&mut self, rhs: &Complement<X>) {
+        crate::difference_assign_by_search(self, rhs)
+    }
+}
+
+impl<T, B, X, const N: usize> BitSymmetricDifferenceAssign<Complement<X>> for Layered<T, B, N>
+where
+    Self: BitTest + BitSet + BitUnset,
+    Complement<X>: BitSearch,
+{
+    fn symmetric_difference_assign(&mut self, rhs: &Complement<X>) {
+        crate::symmetric_difference_assign_by_search(self, rhs)
+    }
+}
+
+impl<T, B, const N: usize> PtrEq for Layered<T, B, N>
+where
+    B: PtrEq,
+{
+    fn ptr_eq(&self, other: &Self) -> bool {
+        self.bottom
+            .iter()
+            .zip(&other.bottom)
+            .all(|(lhs, rhs)| lhs.ptr_eq(rhs))
+    }
+}
+
+/// Snapshots of `Layered` bit-sets with shared bottom layers, e.g. `ArcLayered`.
+///
+/// Clone copies only pointers to bottom layers and mutation copies only touched bottom layer.
+/// Bottom layers shared between snapshots are skipped when snapshots are compared.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut frame = ArcLayered::<Bits4096>::empty();
+/// frame.set_range(0..100_000);
+///
+/// let snapshot = frame.clone();
+/// frame.unset(70_000);
+/// frame.set(200_000);
+///
+/// assert!(frame.changed_leaves(&snapshot).eq([17, 48]));
+/// assert!(frame.diff(&snapshot).eq([70_000, 200_000]));
+/// assert!(!frame.shared_eq(&snapshot));
+///
+/// frame.set(70_000);
+/// frame.unset(200_000);
+/// assert!(frame.shared_eq(&snapshot) && !frame.ptr_eq(&snapshot));
+/// ```
+impl<T, B, const N: usize> Layered<T, B, N>
+where
+    B: PtrEq,
+{
+    /// Returns indices of bottom layers that are not shared with `other`.
+    /// Bits in all other bottom layers are same in both bit-sets.
+    pub fn changed_leaves<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = usize> + 'a {
+        (0..N).filter(move |&t| !self.bottom[t].ptr_eq(&other.bottom[t]))
+    }
+
+    /// Returns true if both bit-sets have same bits set.
+    /// Only bottom layers that are not shared are compared.
+    pub fn shared_eq(&self, other: &Self) -> bool
+    where
+        B: BitSubset,
+    {
+        self.changed_leaves(other).all(|t| {
+            self.bottom[t].is_subset_of(&other.bottom[t])
+                && other.bottom[t].is_subset_of(&self.bottom[t])
+        })
+    }
+
+    /// Returns iterator over indices of bits that are set in only one of bit-sets.
+    /// Only bottom layers that are not shared are searched.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = usize> + 'a
+    where
+        B: BitSearch + BitSetLimit,
+    {
+        self.changed_leaves(other).flat_map(move |t| {
+            SetBits::new(SymmetricDifference(&self.bottom[t], &other.bottom[t]))
+                .map(move |b| t * (B::MAX_SET_INDEX + 1) + b)
+        })
+    }
+}
+
+impl<'a, T, B, const N: usize> IntoIterator for &'a Layered<T, B, N>
+where
+    T: BitSearch + BitSetLimit,
+    B: BitSearch + BitCount + BitSetLimit,
+{
+    type Item = usize;
+    type IntoIter = LayeredIter<'a, T, B, N>;
+
+    fn into_iter(self) -> LayeredIter<'a, T, B, N> {
+        self.iter()
+    }
+}
+
+/// Iterator over indices of bits set in `Layered` bit-set.
+///
+/// Walks set bits of the top layer and iterates over each non-empty bottom layer set.
+#[derive(Clone, Debug)]
+pub struct LayeredIter<'a, T, B, const N: usize> {
+    top: SetBits<&'a T>,
+    bottom: &'a [B; N],
+    front: Option<(usize, SetBits<&'a B>)>,
+    back: Option<(usize, SetBits<&'a B>)>,
+    /// Number of bits set not yielded yet.
+    remaining: usize,
+}
+
+impl<T, B, const N: usize> Iterator for LayeredIter<'_, T, B, N>
+where
+    T: BitSearch,
+    B: BitSearch + BitSetLimit,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if let Some((t, bits)) = &mut self.front {
+                if let Some(b) = bits.next() {
+                    self.remaining -= 1;
+                    return Some(*t * (B::MAX_SET_INDEX + 1) + b);
+                }
+                self.front = None;
+            }
+
+            match self.top.next() {
+                Some(t) => self.front = Some((t, SetBits::new(&self.bottom[t]))),
+                None => {
+                    let (t, bits) = self.back.as_mut()?;
+                    return match bits.next() {
+                        Some(b) => {
+                            self.remaining -= 1;
+                            Some(*t * (B::MAX_SET_INDEX + 1) + b)
+                        }
+                        None => {
+                            self.back = None;
+                            None
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, B, const N: usize> DoubleEndedIterator for LayeredIter<'_, T, B, N>
+where
+    T: BitSearch + BitSearchRev,
+    B: BitSearch + BitSearchRev + BitSetLimit,
+{
+    fn next_back(&mut self) -> Option<usize> {
+        loop {
+            if let Some((t, bits)) = &mut self.back {
+                if let Some(b) = bits.next_back() {
+                    self.remaining -= 1;
+                    return Some(*t * (B::MAX_SET_INDEX + 1) + b);
+                }
+                self.back = None;
+            }
+
+            match self.top.next_back() {
+                Some(t) => self.back = Some((t, SetBits::new(&self.bottom[t]))),
+                None => {
+                    let (t, bits) = self.front.as_mut()?;
+                    return match bits.next_back() {
+                        Some(b) => {
+                            self.remaining -= 1;
+                            Some(*t * (B::MAX_SET_INDEX + 1) + b)
+                        }
+                        None => {
+                            self.front = None;
+                            None
+                        }
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl<T, B, const N: usize> ExactSizeIterator for LayeredIter<'_, T, B, N>
+where
+    T: BitSearch,
+    B: BitSearch + BitSetLimit,
+{
+}
+
+impl<T, B, const N: usize> FusedIterator for LayeredIter<'_, T, B, N>
+where
+    T: BitSearch,
+    B: BitSearch + BitSetLimit,
+{
+}
+
+impl<T, B, TR, BR, const N: usize> BitSubset<Layered<TR, BR, N>> for Layered<T, B, N>
+where
+    T: BitSearch,
+    B: BitSubset<BR>,
+    TR: BitTest,
+{
+    fn is_subset_of(&self, rhs: &Layered<TR, BR, N>) -> bool {
+        let mut t = 0;
+
+        // Empty bottom layers are skipped using top layer.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            if !rhs.top.test(t_set) || !self.bottom[t_set].is_subset_of(&rhs.bottom[t_set]) {
+                return false;
+            }
+            t = t_set + 1;
+        }
+
+        true
+    }
+}
+
+impl<T, B, X, const N: usize> BitSubset<Complement<X>> for Layered<T, B, N>
+where
+    Self: BitDisjoint<X>,
+{
+    fn is_subset_of(&self, rhs: &Complement<X>) -> bool {
+        self.is_disjoint(&rhs.0)
+    }
+}
+
+impl<T, B, U, const N: usize> BitSubset<Option<U>> for Layered<T, B, N>
+where
+    T: BitTestNone,
+    Self: BitSubset<U>,
+{
+    fn is_subset_of(&self, rhs: &Option<U>) -> bool {
+        match rhs {
+            None => self.test_none(),
+            Some(rhs) => self.is_subset_of(rhs),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B, U, const N: usize> BitSubset<Box<U>> for Layered<T, B, N>
+where
+    Self: BitSubset<U>,
+{
+    fn is_subset_of(&self, rhs: &Box<U>) -> bool {
+        self.is_subset_of(&**rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B, U, const N: usize> BitSubset<Rc<U>> for Layered<T, B, N>
+where
+    Self: BitSubset<U>,
+{
+    fn is_subset_of(&self, rhs: &Rc<U>) -> bool {
+        self.is_subset_of(&**rhs)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T, B, U, const N: usize> BitSubset<Arc<U>> for Layered<T, B, N>
+where
+    Self: BitSubset<U>,
+{
+    fn is_subset_of(&self, rhs: &Arc<U>) -> bool {
+        self.is_subset_of(&**rhs)
+    }
+}
+
+impl<T, B, TR, BR, const N: usize> BitDisjoint<Layered<TR, BR, N>> for Layered<T, B, N>
+where
+    T: BitSearch,
+    B: BitDisjoint<BR>,
+    TR: BitTest,
+{
+    fn is_disjoint(&self, rhs: &Layered<TR, BR, N>) -> bool {
+        let mut t = 0;
+
+        // Empty bottom layers are skipped using top layer.
+        while let Some(t_set) = self.top.find_first_set(t) {
+            if t_set >= N {
+                break;
+            }
+
+            if rhs.top.test(t_set) && !self.bottom[t_set].is_disjoint(&rhs.bottom[t_set]) {
+                return false;
+            }
+            t = t_set + 1;
+        }
+
+        true
+    }
+}
+
+impl<T, B, X, const N: usize> BitDisjoint<Complement<X>> for Layered<T, B, N>
+where
+    Self: BitSubset<X>,
+{
+    fn is_disjoint(&self, rhs: &Complement<X>) -> bool {
+        self.is_subset_of(&rhs.0)
+    }
+}
+
+impl<T, B, U, const N: usize> BitDisjoint<Option<U>> for Layered<T, B, N>
+where
+    Self: BitDisjoint<U>,
+{
+    fn is_disjoint(&self, rhs: &Option<U>) -> bool {
+        match rhs {
+            None => true,
+            Some(rhs) => self.is_disjoint(rhs),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B, U, const N: usize> BitDisjoint<Box<U>> for Layered<T, B, N>
+where
+    Self: BitDisjoint<U>,
+{
+    fn is_disjoint(&self, rhs: &Box<U>) -> bool {
+        self.is_disjoint(&**rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, B, U, const N: usize> BitDisjoint<Rc<U>> for Layered<T, B, N>
+where
+    Self: BitDisjoint<U>,
+{
+    fn is_disjoint(&self, rhs: &Rc<U>) -> bool {
+        self.is_disjoint(&**rhs)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T, B, U, const N: usize> BitDisjoint<Arc<U>> for Layered<T, B, N>
+where
+    Self: BitDisjoint<U>,
+{
+    fn is_disjoint(&self, rhs: &Arc<U>) -> bool {
+        self.is_disjoint(&**rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            test_util::{check_rank_select, check_runs, from_model, Rng},
+            Bits1024, Bits4096,
+        },
+    };
+
+    /// Checks that top layer has bits set exactly for non-empty bottom layers.
+    fn check_top_layer<T, B, const N: usize>(set: &Layered<T, B, N>)
+    where
+        T: BitTest,
+        B: BitTestNone,
+    {
+        for (t, bottom) in set.bottom.iter().enumerate() {
+            assert_eq!(set.top.test(t), !bottom.test_none(), "bottom layer {}", t);
+        }
+    }
+
+    #[test]
+    fn runs_across_bottom_layers_match_model() {
+        let mut rng = Rng::new(24);
+        check_runs::<Bits1024>(&mut rng, 40);
+        check_runs::<Bits4096>(&mut rng, 200);
+        #[cfg(feature = "alloc")]
+        check_runs::<crate::Bits65536>(&mut rng, 3000);
+    }
+
+    #[test]
+    fn rank_select_match_model() {
+        let mut rng = Rng::new(5);
+        check_rank_select::<Bits1024>(&mut rng, 40);
+        check_rank_select::<Bits4096>(&mut rng, 200);
+        #[cfg(feature = "alloc")]
+        check_rank_select::<crate::Bits65536>(&mut rng, 3000);
+    }
+
+    #[test]
+    fn rank_directory_matches_model() {
+        let mut rng = Rng::new(5);
+        for max_run in [1, 30, 500] {
+            let model = rng.runs(4096, max_run);
+            let set: Bits4096 = from_model(&model);
+            let ones: Vec<usize> = (0..4096).filter(|&idx| model[idx]).collect();
+
+            let directory = set.rank_directory();
+            assert_eq!(directory.count_ones(), ones.len());
+            for idx in 0..4100 {
+                assert_eq!(directory.rank(idx), ones.partition_point(|&i| i < idx));
+            }
+            for k in 0..=ones.len() {
+                assert_eq!(directory.select(k), ones.get(k).copied());
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_difference_assign_repairs_top_layer() {
+        let mut rng = Rng::new(11);
+        for _ in 0..50 {
+            let model = rng.runs(4096, 100);
+            let other = rng.overlapping(&model, 64);
+            let expected: Vec<bool> = model.iter().zip(&other).map(|(a, b)| a != b).collect();
+
+            let mut set: Bits4096 = from_model(&model);
+            set.symmetric_difference_assign(&from_model::<Bits4096>(&other));
+            check_top_layer(&set);
+            assert!(set.iter_set().eq((0..4096).filter(|&idx| expected[idx])));
+            assert_eq!(set.test_none(), !expected.contains(&true));
+
+            // Applying it again restores original bits.
+            set.symmetric_difference_assign(&from_model::<Bits4096>(&other));
+            check_top_layer(&set);
+            assert!(set.iter_set().eq((0..4096).filter(|&idx| model[idx])));
+
+            // Lazy operands are applied bit by bit, top layer must follow as well.
+            let mut set: Bits1024 = from_model(&model[..1024]);
+            let rhs = Intersection(
+                Complement(from_model::<Bits1024>(&other[..1024])),
+                Bits1024::full(),
+            );
+            set.symmetric_difference_assign(&rhs);
+            check_top_layer(&set);
+            assert!(set
+                .iter_set()
+                .eq((0..1024).filter(|&idx| model[idx] == other[idx])));
+        }
+
+        let mut set = Bits4096::full();
+        set.symmetric_difference_assign(&Bits4096::full());
+        check_top_layer(&set);
+        assert!(set.test_none());
+    }
+}
@@ -6,21 +6,179 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+/// Implements in-place operations with lazy wrappers on the right hand side
+/// by searching bits set.
+macro_rules! impl_assign_by_search {
+    (impl[$($generics:tt)*] for $ty:ty;) => {};
+
+    (impl[$($generics:tt)*] for $ty:ty; $rhs:ident $($tail:ident)*) => {
+        impl<X, Y, $($generics)*> crate::ops::BitUnionAssign<$rhs<X, Y>> for $ty
+        where
+            Self: crate::ops::BitSet,
+            $rhs<X, Y>: crate::ops::BitSearch,
+        {
+            fn union_assign(&mut self, rhs: &$rhs<X, Y>) {
+                crate::union_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<X, Y, $($generics)*> crate::ops::BitIntersectionAssign<$rhs<X, Y>> for $ty
+        where
+            Self: crate::ops::BitSearch + crate::ops::BitUnset,
+            $rhs<X, Y>: crate::ops::BitTest,
+        {
+            fn intersect_assign(&mut self, rhs: &$rhs<X, Y>) {
+                crate::intersect_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<X, Y, $($generics)*> crate::ops::BitDifferenceAssign<$rhs<X, Y>> for $ty
+        where
+            Self: crate::ops::BitSearch + crate::ops::BitUnset,
+            $rhs<X, Y>: crate::ops::BitTest,
+        {
+            fn difference_assign(&mut self, rhs: &$rhs<X, Y>) {
+                crate::difference_assign_by_search(self, rhs)
+            }
+        }
+
+        impl<X, Y, $($generics)*> crate::ops::BitSymmetricDifferenceAssign<$rhs<X, Y>> for $ty
+        where
+            Self: crate::ops::BitTest + crate::ops::BitSet + crate::ops::BitUnset,
+            $rhs<X, Y>: crate::ops::BitSearch,
+        {
+            fn symmetric_difference_assign(&mut self, rhs: &$rhs<X, Y>) {
+                crate::symmetric_difference_assign_by_search(self, rhs)
+            }
+        }
+
+        impl_assign_by_search!(impl[$($generics)*] for $ty; $($tail)*);
+    };
+}
+
+/// Implements operators for bit-set type by delegating to bit-set traits.
+macro_rules! impl_bit_operators {
+    (impl[] for $ty:ty) => {
+        impl_bit_operators!(@impl[Y][] for $ty);
+    };
+
+    (impl[$($generics:tt)*] for $ty:ty) => {
+        impl_bit_operators!(@impl[$($generics)*, Y][$($generics)*] for $ty);
+    };
+
+    (@impl[$($with_rhs:tt)*][$($generics:tt)*] for $ty:ty) => {
+        impl<$($with_rhs)*> core::ops::BitOr<Y> for $ty
+        where
+            Self: crate::ops::BitUnion<Y>,
+        {
+            type Output = <Self as crate::ops::BitUnion<Y>>::Output;
+
+            fn bitor(self, rhs: Y) -> Self::Output {
+                crate::ops::BitUnion::union(self, rhs)
+            }
+        }
+
+        impl<$($with_rhs)*> core::ops::BitAnd<Y> for $ty
+        where
+            Self: crate::ops::BitIntersection<Y>,
+        {
+            type Output = <Self as crate::ops::BitIntersection<Y>>::Output;
+
+            fn bitand(self, rhs: Y) -> Self::Output {
+                crate::ops::BitIntersection::intersection(self, rhs)
+            }
+        }
+
+        impl<$($with_rhs)*> core::ops::BitXor<Y> for $ty
+        where
+            Self: crate::ops::BitSymmetricDifference<Y>,
+        {
+            type Output = <Self as crate::ops::BitSymmetricDifference<Y>>::Output;
+
+            fn bitxor(self, rhs: Y) -> Self::Output {
+                crate::ops::BitSymmetricDifference::symmetric_difference(self, rhs)
+            }
+        }
+
+        impl<$($with_rhs)*> core::ops::Sub<Y> for $ty
+        where
+            Self: crate::ops::BitDifference<Y>,
+        {
+            type Output = <Self as crate::ops::BitDifference<Y>>::Output;
+
+            fn sub(self, rhs: Y) -> Self::Output {
+                crate::ops::BitDifference::difference(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> core::ops::Not for $ty
+        where
+            Self: crate::ops::BitComplement,
+        {
+            type Output = <Self as crate::ops::BitComplement>::Output;
+
+            fn not(self) -> Self::Output {
+                crate::ops::BitComplement::complement(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+mod any;
+mod array;
+mod atomic;
+mod buddy;
 mod complement;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod concurrent;
 mod difference;
+#[cfg(feature = "alloc")]
+mod dyn_bits;
+mod dynamic;
+mod id_alloc;
 mod indirect;
 mod intersection;
+mod intersection_all;
+mod iter;
 mod layered;
 mod ops;
 mod option;
+#[macro_use]
 mod primitive;
+mod slice;
+mod symmetric_difference;
+#[cfg(test)]
+mod test_util;
 mod union;
+mod union_all;
 
 pub use self::{
-    complement::Complement, difference::Difference, intersection::Intersection, layered::Layered,
-    ops::*, union::Union,
+    buddy::BuddyAllocator,
+    complement::Complement,
+    difference::Difference,
+    id_alloc::{CapacityExceeded, IdAllocator},
+    intersection::Intersection,
+    intersection_all::IntersectionAll,
+    iter::SetBits,
+    layered::{Layered, LayeredIter, RankDirectory},
+    ops::*,
+    symmetric_difference::SymmetricDifference,
+    union::Union,
+    union_all::UnionAll,
 };
 
+#[cfg(feature = "alloc")]
+pub use self::{
+    any::AnyBitSet,
+    dyn_bits::DynBits,
+    id_alloc::{GenerationalIdAllocator, Handle},
+    union_all::UnionAllIter,
+};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use self::concurrent::ConcurrentLayered;
+
 pub type Bits1 = bool;
 pub type Bits8 = u8;
 pub type Bits16 = u16;
@@ -72,6 +230,110 @@ pub type Bits33554432 = Layered<u64, Option<alloc::boxed::Box<Bits524288>>, 64>;
 #[cfg(feature = "alloc")]
 pub type Bits67108864 = Layered<u64, Option<alloc::boxed::Box<Bits1048576>>, 64>;
 
+pub type IdAllocator64 = IdAllocator<Bits64>;
+pub type IdAllocator128 = IdAllocator<Bits128>;
+pub type IdAllocator256 = IdAllocator<Bits256>;
+pub type IdAllocator512 = IdAllocator<Bits512>;
+pub type IdAllocator1024 = IdAllocator<Bits1024>;
+pub type IdAllocator2048 = IdAllocator<Bits2048>;
+pub type IdAllocator4096 = IdAllocator<Bits4096>;
+pub type IdAllocator8192 = IdAllocator<Bits8192>;
+pub type IdAllocator16384 = IdAllocator<Bits16384>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator32768 = IdAllocator<Bits32768>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator65536 = IdAllocator<Bits65536>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator131072 = IdAllocator<Bits131072>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator262144 = IdAllocator<Bits262144>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator524288 = IdAllocator<Bits524288>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator1048576 = IdAllocator<Bits1048576>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator2097152 = IdAllocator<Bits2097152>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator4194304 = IdAllocator<Bits4194304>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator8388608 = IdAllocator<Bits8388608>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator16777216 = IdAllocator<Bits16777216>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator33554432 = IdAllocator<Bits33554432>;
+
+#[cfg(feature = "alloc")]
+pub type IdAllocator67108864 = IdAllocator<Bits67108864>;
+
+/// Persistent layered bit-set with bottom layers shared between clones.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub type ArcLayered<B> = Layered<u64, Option<alloc::sync::Arc<B>>, 64>;
+
+/// Persistent layered bit-set with bottom layers shared between clones, for single-threaded use.
+#[cfg(feature = "alloc")]
+pub type RcLayered<B> = Layered<u64, Option<alloc::rc::Rc<B>>, 64>;
+
+// Every bit-set alias and array of bit-sets supports all operations.
+const _: fn() = || {
+    fn assert_ultimate<T: UltimateBitSet>() {}
+
+    assert_ultimate::<Bits1>();
+    assert_ultimate::<Bits8>();
+    assert_ultimate::<Bits16>();
+    assert_ultimate::<Bits32>();
+    assert_ultimate::<Bits64>();
+    assert_ultimate::<Bits128>();
+
+    assert_ultimate::<Bits256>();
+    assert_ultimate::<Bits512>();
+    assert_ultimate::<Bits1024>();
+    assert_ultimate::<Bits2048>();
+    assert_ultimate::<Bits4096>();
+    assert_ultimate::<Bits8192>();
+    assert_ultimate::<Bits16384>();
+
+    assert_ultimate::<[bool; 64]>();
+    assert_ultimate::<[[u64; 4]; 8]>();
+    assert_ultimate::<[Bits4096; 16]>();
+
+    #[cfg(feature = "alloc")]
+    {
+        assert_ultimate::<Bits32768>();
+        assert_ultimate::<Bits65536>();
+        assert_ultimate::<Bits131072>();
+        assert_ultimate::<Bits262144>();
+        assert_ultimate::<Bits524288>();
+        assert_ultimate::<Bits1048576>();
+        assert_ultimate::<Bits2097152>();
+        assert_ultimate::<Bits4194304>();
+        assert_ultimate::<Bits8388608>();
+        assert_ultimate::<Bits16777216>();
+        assert_ultimate::<Bits33554432>();
+        assert_ultimate::<Bits67108864>();
+        assert_ultimate::<AnyBitSet>();
+        assert_ultimate::<[Option<alloc::boxed::Box<Bits512>>; 8]>();
+        assert_ultimate::<RcLayered<Bits512>>();
+        assert_ultimate::<RcLayered<RcLayered<Bits4096>>>();
+    }
+
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    {
+        assert_ultimate::<ArcLayered<Bits4096>>();
+        assert_ultimate::<ArcLayered<ArcLayered<Bits4096>>>();
+    }
+};
+
 const fn max(a: usize, b: usize) -> usize {
     if a > b {
         a
@@ -88,6 +350,199 @@ const fn min(a: usize, b: usize) -> usize {
     }
 }
 
+/// Converts range bounds into pair of inclusive bounds.
+/// Unbounded end is replaced with `unbounded_end`.
+/// Returns `None` if resulting range is empty.
+fn range_bounds<R>(range: &R, unbounded_end: usize) -> Option<(usize, usize)>
+where
+    R: core::ops::RangeBounds<usize>,
+{
+    use core::ops::Bound;
+
+    let start = match range.start_bound() {
+        Bound::Included(bound) => *bound,
+        Bound::Excluded(bound) => bound.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(bound) => *bound,
+        Bound::Excluded(bound) => bound.checked_sub(1)?,
+        Bound::Unbounded => unbounded_end,
+    };
+
+    if start > end {
+        None
+    } else {
+        Some((start, end))
+    }
+}
+
+/// Converts range bounds into pair of inclusive bounds clamped to `max`.
+/// Returns `None` if resulting range is empty.
+fn inclusive_bounds<R>(range: &R, max: usize) -> Option<(usize, usize)>
+where
+    R: core::ops::RangeBounds<usize>,
+{
+    let (start, end) = range_bounds(range, max)?;
+    let end = min(end, max);
+
+    if start > end {
+        None
+    } else {
+        Some((start, end))
+    }
+}
+
+/// Counts bits set in the range by searching them one by one.
+fn count_by_search<S, R>(set: &S, range: R) -> usize
+where
+    S: ops::BitSearch,
+    R: core::ops::RangeBounds<usize>,
+{
+    let (start, end) = match inclusive_bounds(&range, usize::MAX) {
+        None => return 0,
+        Some(bounds) => bounds,
+    };
+
+    let mut count = 0;
+    let mut lower_bound = start;
+
+    while let Some(idx) = set.find_first_set(lower_bound) {
+        if idx > end {
+            break;
+        }
+        count += 1;
+        if idx == end {
+            break;
+        }
+        lower_bound = idx + 1;
+    }
+
+    count
+}
+
+/// Counts bits set in both bit-sets in the range.
+/// Common bits are counted word at a time where both bit-sets expose words,
+/// otherwise they are searched one by one.
+fn count_intersection<T, U, R>(lhs: &T, rhs: &U, range: R) -> usize
+where
+    T: ops::BitSearch,
+    U: ops::BitSearch,
+    R: core::ops::RangeBounds<usize>,
+{
+    let (start, end) = match inclusive_bounds(&range, usize::MAX) {
+        None => return 0,
+        Some(bounds) => bounds,
+    };
+
+    let both = intersection::Intersection(lhs, rhs);
+    let mut count = 0;
+    let mut lower_bound = start;
+
+    // Search skips words without common bits.
+    while let Some(idx) = both.find_first_set(lower_bound) {
+        if idx > end {
+            break;
+        }
+
+        let w = idx / 64;
+        match (lhs.word64(w), rhs.word64(w)) {
+            (Some(t), Some(u)) => {
+                // There are no common bits from `lower_bound` to `idx`.
+                let mut word = t & u & (u64::MAX << (idx % 64));
+                if w == end / 64 {
+                    word &= u64::MAX >> (63 - end % 64);
+                    return count + word.count_ones() as usize;
+                }
+                count += word.count_ones() as usize;
+                lower_bound = (w + 1) * 64;
+            }
+            _ => {
+                count += 1;
+                if idx == end {
+                    break;
+                }
+                lower_bound = idx + 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Sets bits in `set` for each bit set in `rhs`, searching them one by one.
+fn union_assign_by_search<S, R>(set: &mut S, rhs: &R)
+where
+    S: ?Sized + ops::BitSet,
+    R: ops::BitSearch,
+{
+    let mut lower_bound = 0;
+    while let Some(idx) = rhs.find_first_set(lower_bound) {
+        set.set(idx);
+        if idx == usize::MAX {
+            break;
+        }
+        lower_bound = idx + 1;
+    }
+}
+
+/// Unsets bits in `set` for each bit unset in `rhs`, searching bits set in `set` one by one.
+fn intersect_assign_by_search<S, R>(set: &mut S, rhs: &R)
+where
+    S: ?Sized + ops::BitSearch + ops::BitUnset,
+    R: ops::BitTest,
+{
+    let mut lower_bound = 0;
+    while let Some(idx) = set.find_first_set(lower_bound) {
+        if !rhs.test(idx) {
+            set.unset(idx);
+        }
+        if idx == usize::MAX {
+            break;
+        }
+        lower_bound = idx + 1;
+    }
+}
+
+/// Unsets bits in `set` for each bit set in `rhs`, searching bits set in `set` one by one.
+fn difference_assign_by_search<S, R>(set: &mut S, rhs: &R)
+where
+    S: ?Sized + ops::BitSearch + ops::BitUnset,
+    R: ops::BitTest,
+{
+    let mut lower_bound = 0;
+    while let Some(idx) = set.find_first_set(lower_bound) {
+        if rhs.test(idx) {
+            set.unset(idx);
+        }
+        if idx == usize::MAX {
+            break;
+        }
+        lower_bound = idx + 1;
+    }
+}
+
+/// Flips bits in `set` for each bit set in `rhs`, searching them one by one.
+fn symmetric_difference_assign_by_search<S, R>(set: &mut S, rhs: &R)
+where
+    S: ?Sized + ops::BitTest + ops::BitSet + ops::BitUnset,
+    R: ops::BitSearch,
+{
+    let mut lower_bound = 0;
+    while let Some(idx) = rhs.find_first_set(lower_bound) {
+        if set.test(idx) {
+            set.unset(idx);
+        } else {
+            set.set(idx);
+        }
+        if idx == usize::MAX {
+            break;
+        }
+        lower_bound = idx + 1;
+    }
+}
+
 fn make_array<F, O, const N: usize>(mut f: F) -> [O; N]
 where
     F: FnMut() -> O,
@@ -123,7 +578,7 @@ fn map2_arrays<T, U, F, O, const N: usize>(
 where
     F: FnMut(T, U) -> O,
 {
-    use core::{array::IntoIter, mem::MaybeUninit};
+    use core::mem::MaybeUninit;
 
     let mut result = unsafe {
         // # Safe
@@ -131,11 +586,7 @@ where
         MaybeUninit::<[MaybeUninit<O>; N]>::uninit().assume_init()
     };
 
-    for ((slot, left_elem), right_elem) in result
-        .iter_mut()
-        .zip(IntoIter::new(left_array))
-        .zip(IntoIter::new(right_array))
-    {
+    for ((slot, left_elem), right_elem) in result.iter_mut().zip(left_array).zip(right_array) {
         unsafe {
             // # Safe. Writing to unit but valid and properly aligned memory.
             // Leaks all previously written elements on panic. Still safe.
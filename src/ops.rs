@@ -1,5 +1,7 @@
 //! Traits to define operations on bit-set.
 
+use crate::{complement::Complement, iter::SetBits};
+
 pub trait BitEmpty {
     /// Returns empty bitset for which all bits are unset.
     fn empty() -> Self;
@@ -7,6 +9,17 @@ pub trait BitEmpty {
 
 pub trait BitFull {
     /// Returns full bitset for which all bits are set.
+    ///
+    /// For bit-sets with `MAX_SET_INDEX` this means all bits up to `MAX_SET_INDEX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// assert_eq!(u16::full(), u16::MAX);
+    /// assert!(<[u8; 4]>::full().test_all());
+    /// assert!(Complement::<u32>::empty().test_none());
+    /// ```
     fn full() -> Self;
 }
 
@@ -28,6 +41,21 @@ pub trait BitTestNone {
 /// This trait should be implemented for most bit-set.
 pub trait BitTestAll {
     /// Tests if all bits are set.
+    ///
+    /// For bit-sets with `MAX_SET_INDEX` this means all bits up to `MAX_SET_INDEX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// assert!(u8::MAX.test_all());
+    /// assert!(!0x7fu8.test_all());
+    ///
+    /// // Complement of full bit-set has no bits set and vice versa.
+    /// assert!(Complement(u8::MAX).test_none());
+    /// assert!(Complement(0u8).test_all());
+    /// assert!(!Complement(0x7fu8).test_none());
+    /// ```
     fn test_all(&self) -> bool;
 }
 
@@ -115,6 +143,131 @@ pub trait BitUnset: BitUnsetLimit {
     unsafe fn unset_unchecked(&mut self, idx: usize);
 }
 
+/// Sets range of bits.
+/// This trait should be implemented for mutable bit-sets that can set many bits at once faster than one by one.
+pub trait BitSetRange: BitSet {
+    /// Sets all bits in specified range.
+    /// Unbounded range end is treated as `MAX_SET_INDEX`.
+    ///
+    /// # Panics
+    ///
+    /// Calling with range that contains indices greater than `MAX_SET_INDEX` should panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits4096::empty();
+    /// set.set_range(100..3000);
+    /// assert!(set.test_range_all(100..3000));
+    /// assert!(!set.test_range_any(..100));
+    /// assert!(!set.test_range_any(3000..));
+    /// ```
+    #[inline]
+    fn set_range<R>(&mut self, range: R)
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        if let Some((first, last)) = crate::range_bounds(&range, Self::MAX_SET_INDEX) {
+            assert!(last <= Self::MAX_SET_INDEX, "Idx out of bounds");
+            unsafe {
+                // # Safe
+                // Condition is checked above.
+                self.set_range_unchecked(first, last)
+            }
+        }
+    }
+
+    /// Sets all bits from `first` to `last` inclusive.
+    ///
+    /// # Safety
+    ///
+    /// Calling with `first > last` or `last > MAX_SET_INDEX` may trigger UB.
+    /// Otherwise behavior is identical to `set_range`, but may produce better optimized code.
+    ///
+    /// Implementations are encouraged to use debug assertions.
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize);
+}
+
+/// Unsets range of bits.
+/// This trait should be implemented for mutable bit-sets that can unset many bits at once faster than one by one.
+pub trait BitUnsetRange: BitUnset {
+    /// Unsets all bits in specified range.
+    /// Unbounded range end is treated as `MAX_UNSET_INDEX`.
+    ///
+    /// # Panics
+    ///
+    /// Calling with range that contains indices greater than `MAX_UNSET_INDEX` should panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits4096::empty();
+    /// set.set_range(..);
+    /// set.unset_range(100..4000);
+    /// assert_eq!(set.count_ones(), 4096 - 3900);
+    /// assert!(!set.test_range_any(100..4000));
+    /// ```
+    #[inline]
+    fn unset_range<R>(&mut self, range: R)
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        if let Some((first, last)) = crate::range_bounds(&range, Self::MAX_UNSET_INDEX) {
+            assert!(last <= Self::MAX_UNSET_INDEX, "Idx out of bounds");
+            unsafe {
+                // # Safe
+                // Condition is checked above.
+                self.unset_range_unchecked(first, last)
+            }
+        }
+    }
+
+    /// Unsets all bits from `first` to `last` inclusive.
+    ///
+    /// # Safety
+    ///
+    /// Calling with `first > last` or `last > MAX_UNSET_INDEX` may trigger UB.
+    /// Otherwise behavior is identical to `unset_range`, but may produce better optimized code.
+    ///
+    /// Implementations are encouraged to use debug assertions.
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize);
+}
+
+/// Test range of bits.
+pub trait BitTestRange {
+    /// Tests if all bits in specified range are set.
+    /// Returns `true` for empty range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use {core::ops::Range, bitsetium::*};
+    /// fn validate<S: BitTestRange + BitTest>(set: &S, mut range: Range<usize>) {
+    ///   assert_eq!(set.test_range_all(range.clone()), range.all(|idx| set.test(idx)));
+    /// }
+    /// ```
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>;
+
+    /// Tests if any bit in specified range is set.
+    /// Returns `false` for empty range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use {core::ops::Range, bitsetium::*};
+    /// fn validate<S: BitTestRange + BitTest>(set: &S, mut range: Range<usize>) {
+    ///   assert_eq!(set.test_range_any(range.clone()), range.any(|idx| set.test(idx)));
+    /// }
+    /// ```
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>;
+}
+
 /// Search for set its.
 pub trait BitSearch {
     /// Searches for first bit set starting with `lower_bound`.
@@ -187,6 +340,581 @@ pub trait BitSearch {
                 Bound::Unbounded => Some(idx),
             })
     }
+
+    /// Returns 64 bits from `64 * idx` to `64 * idx + 63` as a word,
+    /// or `None` if bit-set does not store its bits in words.
+    ///
+    /// Lazy wrappers use words to count bits set in both operands
+    /// 64 bits at once instead of searching them one by one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let set = [0xF0u8, 0x0F, 0, 0, 0, 0, 0, 0, 0xFF];
+    /// assert_eq!(set.word64(0), Some(0x0FF0));
+    /// assert_eq!(set.word64(1), Some(0xFF));
+    /// assert_eq!(set.word64(2), Some(0));
+    /// assert_eq!(Complement(0u8).word64(0), None);
+    /// ```
+    #[inline]
+    fn word64(&self, _idx: usize) -> Option<u64> {
+        None
+    }
+
+    /// Evaluates bit-set into concrete bit-set type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` cannot hold all bits set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut a = Bits4096::empty();
+    /// a.set_range(10..20);
+    ///
+    /// let mut b = Bits4096::empty();
+    /// b.set_range(15..30);
+    ///
+    /// let c: Bits4096 = Difference(&a, &b).materialize();
+    /// assert!(c.iter_set().eq(10..15));
+    /// ```
+    #[inline]
+    fn materialize<S>(&self) -> S
+    where
+        Self: Sized,
+        S: FromBitSearch,
+    {
+        S::from_bit_search(self)
+    }
+
+    /// Evaluates bit-set into concrete bit-set type.
+    /// Returns `None` if `S` cannot hold all bits set.
+    #[inline]
+    fn try_materialize<S>(&self) -> Option<S>
+    where
+        Self: Sized,
+        S: FromBitSearch,
+    {
+        S::try_from_bit_search(self)
+    }
+}
+
+/// Search for set bits in reverse order.
+pub trait BitSearchRev {
+    /// Searches for last bit set ending with `upper_bound`.
+    /// Returns index of last bit set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// fn validate<S: BitSearchRev + BitTest>(set: &S, upper_bound: usize) {
+    ///   match set.find_last_set(upper_bound) {
+    ///     None => assert!((0..=upper_bound).all(|idx| !set.test(idx))),
+    ///     Some(idx) => {
+    ///       assert!(idx <= upper_bound);
+    ///       assert!((idx + 1..=upper_bound).all(|idx| !set.test(idx)));
+    ///       assert!(set.test(idx));
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize>;
+
+    /// Searches for last bit set in specified range.
+    /// Returns index of bit set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use {core::ops::Range, bitsetium::*};
+    /// fn validate<S: BitSearchRev + BitTest>(set: &S, mut range: Range<usize>) {
+    ///   match set.find_set_in_range_rev(range.clone()) {
+    ///     None => assert!(range.all(|idx| !set.test(idx))),
+    ///     Some(idx) => {
+    ///       assert!(idx >= range.start);
+    ///       assert!((idx + 1..range.end).all(|idx| !set.test(idx)));
+    ///       assert!(set.test(idx));
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn find_set_in_range_rev<R>(&self, range: R) -> Option<usize>
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        use core::ops::Bound;
+
+        let upper_bound = match range.end_bound() {
+            Bound::Included(bound) => *bound,
+            Bound::Excluded(bound) => bound.checked_sub(1)?,
+            Bound::Unbounded => usize::MAX,
+        };
+
+        self.find_last_set(upper_bound)
+            .and_then(|idx| match range.start_bound() {
+                Bound::Included(bound) => {
+                    if *bound <= idx {
+                        Some(idx)
+                    } else {
+                        None
+                    }
+                }
+                Bound::Excluded(bound) => {
+                    if *bound < idx {
+                        Some(idx)
+                    } else {
+                        None
+                    }
+                }
+                Bound::Unbounded => Some(idx),
+            })
+    }
+}
+
+/// Count set bits.
+pub trait BitCount {
+    /// Returns number of bits set in specified range.
+    ///
+    /// Result saturates at `usize::MAX`,
+    /// this is only possible for bit-sets with all bits set in whole `usize` range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use {core::ops::Range, bitsetium::*};
+    /// fn validate<S: BitCount + BitTest>(set: &S, range: Range<usize>) {
+    ///   assert_eq!(set.count_in_range(range.clone()), range.filter(|idx| set.test(*idx)).count());
+    /// }
+    /// ```
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>;
+
+    /// Returns number of bits set with index not greater than `upper_bound`.
+    ///
+    /// Lazy wrappers may have virtually unbounded number of bits set,
+    /// e.g. `Complement` of any finite bit-set.
+    /// This method allows to count bits set in such bit-sets up to specified index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let set = Complement(0b1011_0110u8);
+    /// assert_eq!(set.count_ones_bounded(7), 3);
+    /// assert_eq!(set.count_ones_bounded(99), 95);
+    /// ```
+    #[inline]
+    fn count_ones_bounded(&self, upper_bound: usize) -> usize {
+        self.count_in_range(..=upper_bound)
+    }
+
+    /// Returns number of bits set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits65536::empty();
+    /// set.set(1);
+    /// set.set(4000);
+    /// set.set(65535);
+    /// assert_eq!(set.count_ones(), 3);
+    /// assert_eq!(set.count_in_range(2..), 2);
+    /// ```
+    #[inline]
+    fn count_ones(&self) -> usize {
+        self.count_in_range(..)
+    }
+}
+
+/// Rank and select queries.
+pub trait BitRankSelect: BitCount {
+    /// Returns number of bits set with index less than `idx`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let set = 0b1011_0110u8;
+    /// assert_eq!(set.rank(0), 0);
+    /// assert_eq!(set.rank(3), 2);
+    /// assert_eq!(set.rank(100), 5);
+    /// ```
+    #[inline]
+    fn rank(&self, idx: usize) -> usize {
+        self.count_in_range(..idx)
+    }
+
+    /// Returns index of `n`-th bit set, counting from zero.
+    /// Returns `None` if there are no more than `n` bits set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// fn validate<S: BitRankSelect + BitTest>(set: &S, n: usize) {
+    ///   match set.select(n) {
+    ///     None => assert!(set.count_ones() <= n),
+    ///     Some(idx) => {
+    ///       assert!(set.test(idx));
+    ///       assert_eq!(set.rank(idx), n);
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let set = 0b1011_0110u8;
+    /// assert_eq!(set.select(0), Some(1));
+    /// assert_eq!(set.select(4), Some(7));
+    /// assert_eq!(set.select(5), None);
+    /// ```
+    fn select(&self, n: usize) -> Option<usize>;
+}
+
+/// Iteration over set bits.
+/// This trait is implemented for all searchable bit-sets.
+pub trait BitIter: BitSearch + BitSetLimit + Sized {
+    /// Returns iterator over indices of bits set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits4096::empty();
+    /// set.set(3);
+    /// set.set(64);
+    /// set.set(4095);
+    ///
+    /// assert!(set.iter_set().eq([3, 64, 4095]));
+    /// assert!(set.iter_set().rev().eq([4095, 64, 3]));
+    /// ```
+    #[inline]
+    fn iter_set(&self) -> SetBits<&Self> {
+        SetBits::new(self)
+    }
+
+    /// Returns iterator over indices of bits set in specified range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let set = 0b1011_0110u8;
+    /// assert!(set.iter_set_in_range(2..6).eq([2, 4, 5]));
+    /// ```
+    #[inline]
+    fn iter_set_in_range<R>(&self, range: R) -> SetBits<&Self>
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        SetBits::with_range(self, range)
+    }
+
+    /// Returns iterator over indices of bits set that takes ownership of the bit-set.
+    ///
+    /// Useful for lazy wrappers constructed in place.
+    #[inline]
+    fn into_iter_set(self) -> SetBits<Self> {
+        SetBits::new(self)
+    }
+}
+
+impl<T> BitIter for T where T: BitSearch + BitSetLimit {}
+
+/// Search for unset bits.
+pub trait BitSearchUnset {
+    /// Searches for first bit unset starting with `lower_bound`.
+    /// Returns index of first bit unset.
+    ///
+    /// Note that bits with index greater than `MAX_SET_INDEX` are always unset,
+    /// so returned index may exceed `MAX_SET_INDEX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// fn validate<S: BitSearchUnset + BitTest>(set: &S, lower_bound: usize) {
+    ///   match set.find_first_unset(lower_bound) {
+    ///     None => assert!((lower_bound..=lower_bound + 1000).all(|idx| set.test(idx))),
+    ///     Some(idx) => {
+    ///       assert!((lower_bound..idx).all(|idx| set.test(idx)));
+    ///       assert!(!set.test(idx));
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let mut slots = Bits4096::empty();
+    /// slots.set(0);
+    /// slots.set(1);
+    /// slots.set(3);
+    /// assert_eq!(slots.find_first_unset(0), Some(2));
+    /// assert_eq!(slots.find_first_unset(3), Some(4));
+    /// validate(&slots, 0);
+    /// ```
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize>;
+}
+
+/// Iteration over unset bits.
+/// This trait is implemented for all bit-sets that support search for unset bits.
+pub trait BitIterUnset: BitSearchUnset + BitSetLimit + BitUnsetLimit + Sized {
+    /// Returns iterator over indices of bits unset up to `MAX_SET_INDEX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let set = 0b1011_0110u8;
+    /// assert!(set.iter_unset().eq([0, 3, 6]));
+    /// ```
+    #[inline]
+    fn iter_unset(&self) -> SetBits<Complement<&Self>> {
+        SetBits::with_range(Complement(self), ..=Self::MAX_SET_INDEX)
+    }
+}
+
+impl<T> BitIterUnset for T where T: BitSearchUnset + BitSetLimit + BitUnsetLimit {}
+
+/// Search for runs of consecutive bits set or unset.
+///
+/// Provided methods alternate searches for set and unset bits.
+/// Bit-sets that can do better, e.g. primitives and `Layered`, override them.
+/// Runs never extend past `MAX_SET_INDEX`.
+pub trait BitSearchRun: BitSearch + BitSearchUnset + BitSetLimit {
+    /// Searches for first run of `len` consecutive bits set starting with `lower_bound`.
+    /// Returns index of first bit in the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// fn validate<S: BitSearchRun + BitTest>(set: &S, len: usize, lower_bound: usize) {
+    ///   match set.find_first_run_set(len, lower_bound) {
+    ///     None => assert!((lower_bound..=S::MAX_SET_INDEX.min(lower_bound + 1000))
+    ///       .all(|idx| (idx..idx + len).any(|idx| !set.test(idx)))),
+    ///     Some(idx) => {
+    ///       assert!(idx >= lower_bound);
+    ///       assert!((idx..idx + len).all(|idx| set.test(idx)));
+    ///       assert!((lower_bound..idx).all(|start| (start..start + len).any(|idx| !set.test(idx))));
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let set = [0b1110_0111u8, 0b1111_1111, 0b0000_0001];
+    /// assert_eq!(set.find_first_run_set(3, 0), Some(0));
+    /// assert_eq!(set.find_first_run_set(4, 0), Some(5));
+    /// assert_eq!(set.find_first_run_set(12, 0), Some(5));
+    /// assert_eq!(set.find_first_run_set(13, 0), None);
+    /// validate(&set, 4, 1);
+    /// validate(&set, 2, 14);
+    /// ```
+    fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        if len == 0 {
+            return Some(lower_bound).filter(|&idx| idx <= Self::MAX_SET_INDEX);
+        }
+
+        let mut start = lower_bound;
+        loop {
+            start = self.find_first_set(start)?;
+            if start.checked_add(len - 1)? > Self::MAX_SET_INDEX {
+                return None;
+            }
+            match self.find_first_unset(start) {
+                Some(end) if end - start < len => start = end,
+                _ => return Some(start),
+            }
+        }
+    }
+
+    /// Searches for first run of `len` consecutive bits unset starting with `lower_bound`.
+    /// Returns index of first bit in the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// fn validate<S: BitSearchRun + BitTest>(set: &S, len: usize, lower_bound: usize) {
+    ///   match set.find_first_run_unset(len, lower_bound) {
+    ///     None => assert!((lower_bound..=S::MAX_SET_INDEX.min(lower_bound + 1000))
+    ///       .all(|idx| idx + len > S::MAX_SET_INDEX + 1 || (idx..idx + len).any(|idx| set.test(idx)))),
+    ///     Some(idx) => {
+    ///       assert!(idx >= lower_bound && idx + len <= S::MAX_SET_INDEX + 1);
+    ///       assert!((idx..idx + len).all(|idx| !set.test(idx)));
+    ///       assert!((lower_bound..idx).all(|start| (start..start + len).any(|idx| set.test(idx))));
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let mut pages = Bits4096::empty();
+    /// pages.set_range(0..100);
+    /// pages.set(105);
+    /// assert_eq!(pages.find_first_run_unset(5, 0), Some(100));
+    /// assert_eq!(pages.find_first_run_unset(6, 0), Some(106));
+    /// assert_eq!(pages.find_first_run_unset(4000, 0), None);
+    /// validate(&pages, 300, 50);
+    /// ```
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        if len == 0 {
+            return Some(lower_bound).filter(|&idx| idx <= Self::MAX_SET_INDEX);
+        }
+
+        let mut start = lower_bound;
+        loop {
+            start = self.find_first_unset(start)?;
+            if start.checked_add(len - 1)? > Self::MAX_SET_INDEX {
+                return None;
+            }
+            match self.find_first_set(start) {
+                Some(end) if end - start < len => start = end,
+                _ => return Some(start),
+            }
+        }
+    }
+
+    /// Searches for first block of `2^order` bits unset that starts at multiple of `2^order`.
+    /// Returns index of first bit in the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// fn validate<S: BitSearchRun + BitTest>(set: &S, order: u32) {
+    ///   let size = 1 << order;
+    ///   match set.find_aligned_free(order) {
+    ///     None => assert!((0..=S::MAX_SET_INDEX.min(100_000) / size)
+    ///       .all(|block| (block * size..(block + 1) * size).any(|idx| set.test(idx)))),
+    ///     Some(idx) => {
+    ///       assert_eq!(idx % size, 0);
+    ///       assert!(idx + size <= S::MAX_SET_INDEX + 1);
+    ///       assert!((idx..idx + size).all(|idx| !set.test(idx)));
+    ///       assert!((0..idx / size).all(|block| (block * size..(block + 1) * size).any(|idx| set.test(idx))));
+    ///     }
+    ///   }
+    /// }
+    ///
+    /// let mut pages = Bits4096::empty();
+    /// pages.set_range(0..100);
+    /// pages.set(130);
+    /// assert_eq!(pages.find_first_run_unset(32, 0), Some(131));
+    /// assert_eq!(pages.find_aligned_free(5), Some(160));
+    /// assert_eq!(pages.find_aligned_free(2), Some(100));
+    /// assert_eq!(pages.find_aligned_free(12), None);
+    /// validate(&pages, 7);
+    /// ```
+    fn find_aligned_free(&self, order: u32) -> Option<usize> {
+        let size = 1usize.checked_shl(order)?;
+
+        let mut start = 0;
+        loop {
+            start = self.find_first_unset(start)?;
+            let aligned = start.checked_add(size - 1)? & !(size - 1);
+            if aligned.checked_add(size - 1)? > Self::MAX_SET_INDEX {
+                return None;
+            }
+            match self.find_first_set(aligned) {
+                Some(end) if end - aligned < size => start = end + 1,
+                _ => return Some(aligned),
+            }
+        }
+    }
+}
+
+/// Construction of bit-set from any searchable bit-set.
+/// This trait is implemented for all bit-sets that can be constructed empty and have bits set.
+///
+/// Serves as lossless conversion between bit-set representations
+/// and as a way to evaluate lazy wrappers.
+pub trait FromBitSearch: Sized {
+    /// Returns bit-set with same bits set as in `set`.
+    /// Returns `None` if `set` has bits set with index greater than `MAX_SET_INDEX` of this bit-set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut array = [0u64; 64];
+    /// array.set(5);
+    /// array.set(4095);
+    ///
+    /// let set = Bits4096::try_from_bit_search(&array).unwrap();
+    /// assert!(set.iter_set().eq([5, 4095]));
+    ///
+    /// assert!(Bits2048::try_from_bit_search(&array).is_none());
+    /// ```
+    fn try_from_bit_search<S>(set: &S) -> Option<Self>
+    where
+        S: BitSearch;
+
+    /// Returns bit-set with same bits set as in `set`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `set` has bits set with index greater than `MAX_SET_INDEX` of this bit-set.
+    #[inline]
+    fn from_bit_search<S>(set: &S) -> Self
+    where
+        S: BitSearch,
+    {
+        Self::try_from_bit_search(set).expect("Bit-set capacity exceeded")
+    }
+
+    /// Replaces content of this bit-set with bits set in `set`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `set` has bits set with index greater than `MAX_SET_INDEX` of this bit-set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = 0b0110u8;
+    /// set.assign_from(&Union(0b0001u8, 0b1000u8));
+    /// assert_eq!(set, 0b1001);
+    /// ```
+    #[inline]
+    fn assign_from<S>(&mut self, set: &S)
+    where
+        S: BitSearch,
+    {
+        *self = Self::from_bit_search(set);
+    }
+}
+
+impl<T> FromBitSearch for T
+where
+    T: BitEmpty + BitSet,
+{
+    fn try_from_bit_search<S>(set: &S) -> Option<Self>
+    where
+        S: BitSearch,
+    {
+        let mut result = T::empty();
+        let mut lower_bound = 0;
+
+        while let Some(idx) = set.find_first_set(lower_bound) {
+            if idx > T::MAX_SET_INDEX {
+                return None;
+            }
+
+            unsafe {
+                // # Safe
+                // Condition is checked above.
+                result.set_unchecked(idx);
+            }
+
+            if idx == usize::MAX {
+                break;
+            }
+            lower_bound = idx + 1;
+        }
+
+        Some(result)
+    }
 }
 
 /// Trait to get dual set to the given.
@@ -200,7 +928,7 @@ pub trait BitComplement {
 pub trait BitUnion<Rhs = Self> {
     type Output;
 
-    /// Returns bit-set with bits set for each index that has bit set in both of two arguments.
+    /// Returns bit-set with bits set for each index that has bit set in either of two arguments.
     fn union(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -208,7 +936,7 @@ pub trait BitUnion<Rhs = Self> {
 pub trait BitIntersection<Rhs = Self> {
     type Output;
 
-    /// Returns bit-set with bits set for each index that has bit set in either of two arguments.
+    /// Returns bit-set with bits set for each index that has bit set in both of two arguments.
     fn intersection(self, rhs: Rhs) -> Self::Output;
 }
 
@@ -216,10 +944,169 @@ pub trait BitIntersection<Rhs = Self> {
 pub trait BitDifference<Rhs = Self> {
     type Output;
 
-    /// Returns bit-set has bits set for each index that has bit set in exactly one of two arguments.
+    /// Returns bit-set with bits set for each index that has bit set in `self` and unset in `rhs`.
     fn difference(self, rhs: Rhs) -> Self::Output;
 }
 
+/// Symmetric difference of bit-sets.
+pub trait BitSymmetricDifference<Rhs = Self> {
+    type Output;
+
+    /// Returns bit-set with bits set for each index that has bit set in exactly one of two arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// assert_eq!(0b0110u8.symmetric_difference(0b0011u8), 0b0101);
+    ///
+    /// let lazy = SymmetricDifference(0b0110u8, Complement(0b0011u8));
+    /// assert!(!lazy.test(0) && lazy.test(1) && !lazy.test(2) && lazy.test(200));
+    /// assert!(lazy.complement().iter_set().eq([0, 2]));
+    /// ```
+    fn symmetric_difference(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Fluent combinators to build bit-set expressions.
+/// This trait is implemented for all types,
+/// methods are available when corresponding operation is supported.
+///
+/// Operators `|`, `&`, `^`, `-` and `!` do the same for wrappers and `Layered` bit-sets.
+/// Inherent `Option::or` and `Option::and` methods take precedence over this trait.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut a = Bits4096::empty();
+/// let mut b = Bits4096::empty();
+/// let mut c = Bits4096::empty();
+/// a.set_range(0..10);
+/// b.set_range(5..15);
+/// c.set_range(8..20);
+///
+/// let expr = (&a).or(&b).without(&c);
+/// assert!(expr.iter_set().eq(0..8));
+///
+/// let expr = (&a | &b) & !&c;
+/// assert!(expr.iter_set().eq(0..8));
+/// ```
+pub trait BitExpr: Sized {
+    /// Returns union of `self` and `rhs`.
+    fn or<Rhs>(self, rhs: Rhs) -> <Self as BitUnion<Rhs>>::Output
+    where
+        Self: BitUnion<Rhs>,
+    {
+        self.union(rhs)
+    }
+
+    /// Returns intersection of `self` and `rhs`.
+    fn and<Rhs>(self, rhs: Rhs) -> <Self as BitIntersection<Rhs>>::Output
+    where
+        Self: BitIntersection<Rhs>,
+    {
+        self.intersection(rhs)
+    }
+
+    /// Returns bits set in `self` but not in `rhs`.
+    fn without<Rhs>(self, rhs: Rhs) -> <Self as BitDifference<Rhs>>::Output
+    where
+        Self: BitDifference<Rhs>,
+    {
+        self.difference(rhs)
+    }
+
+    /// Returns complement of `self`.
+    fn not(self) -> <Self as BitComplement>::Output
+    where
+        Self: BitComplement,
+    {
+        self.complement()
+    }
+}
+
+impl<T> BitExpr for T {}
+
+/// In-place union of bit-sets.
+pub trait BitUnionAssign<Rhs = Self> {
+    /// Sets bits in `self` for each index that has bit set in `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `rhs` has bits set with index greater than `MAX_SET_INDEX` of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits4096::empty();
+    /// set.set(1);
+    ///
+    /// let mut rhs = Bits4096::empty();
+    /// rhs.set(2000);
+    ///
+    /// set.union_assign(&rhs);
+    /// assert!(set.iter_set().eq([1, 2000]));
+    /// ```
+    fn union_assign(&mut self, rhs: &Rhs);
+}
+
+/// In-place intersection of bit-sets.
+pub trait BitIntersectionAssign<Rhs = Self> {
+    /// Unsets bits in `self` for each index that has bit unset in `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = 0b0110u8;
+    /// set.intersect_assign(&Union(0b0011u8, 0b1000u8));
+    /// assert_eq!(set, 0b0010);
+    /// ```
+    fn intersect_assign(&mut self, rhs: &Rhs);
+}
+
+/// In-place difference of bit-sets.
+pub trait BitDifferenceAssign<Rhs = Self> {
+    /// Unsets bits in `self` for each index that has bit set in `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = [u64::MAX; 4];
+    /// set.difference_assign(&[u64::MAX, 0, 0, 1]);
+    /// assert_eq!(set, [0, u64::MAX, u64::MAX, u64::MAX - 1]);
+    /// ```
+    fn difference_assign(&mut self, rhs: &Rhs);
+}
+
+/// In-place symmetric difference of bit-sets.
+pub trait BitSymmetricDifferenceAssign<Rhs = Self> {
+    /// Flips bits in `self` for each index that has bit set in `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Should panic if `rhs` has bits set with index greater than `MAX_SET_INDEX` of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bitsetium::*;
+    /// let mut set = Bits4096::empty();
+    /// set.set(1);
+    /// set.set(2000);
+    ///
+    /// let mut rhs = Bits4096::empty();
+    /// rhs.set(2000);
+    /// rhs.set(3000);
+    ///
+    /// set.symmetric_difference_assign(&rhs);
+    /// assert!(set.iter_set().eq([1, 3000]));
+    /// ```
+    fn symmetric_difference_assign(&mut self, rhs: &Rhs);
+}
+
 /// Tests any of the following equivalent properties:
 /// - one bit-set is subset of another.
 /// - all bits that are set in one bit-set are set in another.
@@ -253,6 +1140,7 @@ pub trait UltimateBitSet:
     + BitUnion
     + BitIntersection
     + BitDifference
+    + BitSymmetricDifference
     + BitSubset
     + BitDisjoint
 {
@@ -271,7 +1159,183 @@ impl<T> UltimateBitSet for T where
         + BitUnion
         + BitIntersection
         + BitDifference
+        + BitSymmetricDifference
         + BitSubset
         + BitDisjoint
 {
 }
+
+/// Object-safe bit-set trait.
+///
+/// Allows mixing bit-sets of different types and capacities behind `dyn DynBitSet`.
+/// Implemented for all bit-sets that support test, search, count, set and unset operations.
+///
+/// `dyn DynBitSet` implements read-only bit-set traits itself, so references and boxes
+/// can be used with lazy wrappers.
+/// Its `MAX_SET_INDEX` is `usize::MAX`, use `max_set_index` to query actual limit.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut small = 0u64;
+/// let mut large = Bits4096::empty();
+///
+/// let sets: [&mut dyn DynBitSet; 2] = [&mut small, &mut large];
+/// for set in sets {
+///     set.dyn_set(5);
+///     if set.max_set_index() >= 1000 {
+///         set.dyn_set(1000);
+///     }
+/// }
+///
+/// let union = Union(&small as &dyn DynBitSet, &large as &dyn DynBitSet);
+/// assert!(union.iter_set().eq([5, 1000]));
+/// assert_eq!(large.dyn_count_ones(), 2);
+///
+/// let boxed: Vec<Box<dyn DynBitSet>> = vec![Box::new(small), Box::new(large)];
+/// assert_eq!(boxed.iter().map(|set| set.dyn_count_ones()).sum::<usize>(), 3);
+/// assert!(UnionAll(boxed).iter_set().eq([5, 1000]));
+/// ```
+pub trait DynBitSet {
+    /// Returns maximum index of the bit that can be set.
+    fn max_set_index(&self) -> usize;
+
+    /// Tests bit at specified index.
+    fn dyn_test(&self, idx: usize) -> bool;
+
+    /// Find first set bit starting from `lower_bound`.
+    fn dyn_find_first_set(&self, lower_bound: usize) -> Option<usize>;
+
+    /// Returns number of bits set in the inclusive range `first..=last`.
+    fn dyn_count_in_range(&self, first: usize, last: usize) -> usize;
+
+    /// Returns number of bits set.
+    fn dyn_count_ones(&self) -> usize;
+
+    /// Sets bit at specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is greater than `max_set_index`.
+    fn dyn_set(&mut self, idx: usize);
+
+    /// Unsets bit at specified index.
+    fn dyn_unset(&mut self, idx: usize);
+}
+
+impl<T> DynBitSet for T
+where
+    T: BitTest + BitSearch + BitCount + BitSet + BitUnset,
+{
+    fn max_set_index(&self) -> usize {
+        T::MAX_SET_INDEX
+    }
+
+    fn dyn_test(&self, idx: usize) -> bool {
+        self.test(idx)
+    }
+
+    fn dyn_find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        self.find_first_set(lower_bound)
+    }
+
+    fn dyn_count_in_range(&self, first: usize, last: usize) -> usize {
+        self.count_in_range(first..=last)
+    }
+
+    fn dyn_count_ones(&self) -> usize {
+        self.count_ones()
+    }
+
+    fn dyn_set(&mut self, idx: usize) {
+        self.set(idx)
+    }
+
+    fn dyn_unset(&mut self, idx: usize) {
+        self.unset(idx)
+    }
+}
+
+/// Cheap check that two bit-sets share storage.
+///
+/// Implemented for shared pointers and bit-sets built from them,
+/// e.g. `Layered` with `Option<Arc<_>>` bottom layer.
+/// Bit-sets that share storage have same bits set, but not vice versa.
+pub trait PtrEq {
+    /// Returns true if `self` and `other` share storage.
+    fn ptr_eq(&self, other: &Self) -> bool;
+}
+
+/// Bit operations on bit-sets shared between threads.
+///
+/// Methods take `&self` and access bits atomically with specified memory ordering.
+/// Names are distinct from `BitTest`, `BitSet` and `BitUnset` methods,
+/// as those are implemented too, reading with `Ordering::Acquire`
+/// and mutating through exclusive reference.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// let ready = [AtomicU64::new(0), AtomicU64::new(0)];
+/// std::thread::scope(|scope| {
+///     for worker in 0..8 {
+///         let ready = &ready;
+///         scope.spawn(move || ready.atomic_set(worker * 16, Ordering::Release));
+///     }
+/// });
+///
+/// assert!(ready.test_and_set(64, Ordering::AcqRel));
+/// assert!(!ready.test_and_unset(65, Ordering::AcqRel));
+///
+/// let snapshot: [u64; 2] = ready.snapshot(Ordering::Acquire);
+/// assert!(Intersection(snapshot, [u64::MAX, 0]).iter_set().eq([0, 16, 32, 48]));
+/// ```
+pub trait BitAtomic: BitSetLimit {
+    /// Plain bit-set with same layout.
+    type Snapshot;
+
+    /// Tests bit at specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is `Release` or `AcqRel`.
+    fn atomic_test(&self, idx: usize, order: core::sync::atomic::Ordering) -> bool;
+
+    /// Sets bit at specified index and returns its previous state.
+    ///
+    /// # Panics
+    ///
+    /// Calling with `idx > MAX_SET_INDEX` should panic.
+    fn test_and_set(&self, idx: usize, order: core::sync::atomic::Ordering) -> bool;
+
+    /// Unsets bit at specified index and returns its previous state.
+    fn test_and_unset(&self, idx: usize, order: core::sync::atomic::Ordering) -> bool;
+
+    /// Sets bit at specified index.
+    ///
+    /// # Panics
+    ///
+    /// Calling with `idx > MAX_SET_INDEX` should panic.
+    #[inline]
+    fn atomic_set(&self, idx: usize, order: core::sync::atomic::Ordering) {
+        self.test_and_set(idx, order);
+    }
+
+    /// Unsets bit at specified index.
+    #[inline]
+    fn atomic_unset(&self, idx: usize, order: core::sync::atomic::Ordering) {
+        self.test_and_unset(idx, order);
+    }
+
+    /// Loads bits into plain bit-set.
+    /// Arrays are loaded block by block, so snapshot is not atomic as a whole.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is `Release` or `AcqRel`.
+    fn snapshot(&self, order: core::sync::atomic::Ordering) -> Self::Snapshot;
+}
@@ -88,6 +88,60 @@ where
     }
 }
 
+impl<T> BitSetRange for Option<T>
+where
+    T: BitSetRange + BitEmpty,
+{
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        self.get_or_insert_with(T::empty)
+            .set_range_unchecked(first, last)
+    }
+}
+
+impl<T> BitUnsetRange for Option<T>
+where
+    T: BitUnsetRange + BitSetLimit + BitTestNone,
+{
+    unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+        if let Some(bits) = self {
+            if first == 0 && last >= T::MAX_SET_INDEX {
+                // Whole bit-set is cleared.
+                *self = None;
+            } else {
+                bits.unset_range_unchecked(first, last);
+                if bits.test_none() {
+                    *self = None;
+                }
+            }
+        }
+    }
+}
+
+impl<T> BitTestRange for Option<T>
+where
+    T: BitTestRange,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match self {
+            None => crate::range_bounds(&range, usize::MAX).is_none(),
+            Some(bits) => bits.test_range_all(range),
+        }
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match self {
+            None => false,
+            Some(bits) => bits.test_range_any(range),
+        }
+    }
+}
+
 impl<T> BitSearch for Option<T>
 where
     T: BitSearch,
@@ -98,6 +152,94 @@ where
             Some(bits) => bits.find_first_set(lower_bound),
         }
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        match self {
+            None => Some(0),
+            Some(bits) => bits.word64(idx),
+        }
+    }
+}
+
+impl<T> BitCount for Option<T>
+where
+    T: BitCount,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match self {
+            None => 0,
+            Some(bits) => bits.count_in_range(range),
+        }
+    }
+}
+
+impl<T> BitRankSelect for Option<T>
+where
+    T: BitRankSelect,
+{
+    fn select(&self, n: usize) -> Option<usize> {
+        match self {
+            None => None,
+            Some(bits) => bits.select(n),
+        }
+    }
+}
+
+impl<T> BitSearchRev for Option<T>
+where
+    T: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        match self {
+            None => None,
+            Some(bits) => bits.find_last_set(upper_bound),
+        }
+    }
+}
+
+impl<T> BitSearchUnset for Option<T>
+where
+    T: BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        match self {
+            None => Some(lower_bound),
+            Some(bits) => bits.find_first_unset(lower_bound),
+        }
+    }
+}
+
+impl<T> BitSearchRun for Option<T>
+where
+    T: BitSearchRun,
+{
+    fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        match self {
+            None => Some(lower_bound).filter(|&idx| len == 0 && idx <= Self::MAX_SET_INDEX),
+            Some(bits) => bits.find_first_run_set(len, lower_bound),
+        }
+    }
+
+    fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+        match self {
+            None => Some(lower_bound).filter(|&idx| {
+                idx <= Self::MAX_SET_INDEX && len.saturating_sub(1) <= Self::MAX_SET_INDEX - idx
+            }),
+            Some(bits) => bits.find_first_run_unset(len, lower_bound),
+        }
+    }
+
+    fn find_aligned_free(&self, order: u32) -> Option<usize> {
+        match self {
+            None => {
+                Some(0).filter(|_| order < usize::BITS && (1 << order) - 1 <= Self::MAX_SET_INDEX)
+            }
+            Some(bits) => bits.find_aligned_free(order),
+        }
+    }
 }
 
 impl<T> BitComplement for Option<T>
@@ -136,6 +278,7 @@ where
 {
     type Output = Option<T::Output>;
 
+    #[allow(clippy::manual_map)]
     fn intersection(self, rhs: U) -> Option<T::Output> {
         match self {
             None => None,
@@ -150,6 +293,7 @@ where
 {
     type Output = Option<T::Output>;
 
+    #[allow(clippy::manual_map)]
     fn difference(self, rhs: U) -> Option<T::Output> {
         match self {
             None => None,
@@ -158,6 +302,79 @@ where
     }
 }
 
+impl<T, U> BitSymmetricDifference<U> for Option<T>
+where
+    T: BitSymmetricDifference<U>,
+    U: Into<T::Output>,
+{
+    type Output = T::Output;
+
+    fn symmetric_difference(self, rhs: U) -> T::Output {
+        match self {
+            None => rhs.into(),
+            Some(lhs) => lhs.symmetric_difference(rhs),
+        }
+    }
+}
+
+impl<T, U> BitUnionAssign<Option<U>> for Option<T>
+where
+    T: BitUnionAssign<U> + BitEmpty,
+{
+    fn union_assign(&mut self, rhs: &Option<U>) {
+        if let Some(rhs) = rhs {
+            self.get_or_insert_with(T::empty).union_assign(rhs);
+        }
+    }
+}
+
+impl<T, U> BitIntersectionAssign<Option<U>> for Option<T>
+where
+    T: BitIntersectionAssign<U> + BitTestNone,
+{
+    fn intersect_assign(&mut self, rhs: &Option<U>) {
+        match (self.as_mut(), rhs) {
+            (None, _) => {}
+            (Some(_), None) => *self = None,
+            (Some(lhs), Some(rhs)) => {
+                lhs.intersect_assign(rhs);
+                if lhs.test_none() {
+                    *self = None;
+                }
+            }
+        }
+    }
+}
+
+impl<T, U> BitDifferenceAssign<Option<U>> for Option<T>
+where
+    T: BitDifferenceAssign<U> + BitTestNone,
+{
+    fn difference_assign(&mut self, rhs: &Option<U>) {
+        if let (Some(lhs), Some(rhs)) = (self.as_mut(), rhs) {
+            lhs.difference_assign(rhs);
+            if lhs.test_none() {
+                *self = None;
+            }
+        }
+    }
+}
+
+impl<T, U> BitSymmetricDifferenceAssign<Option<U>> for Option<T>
+where
+    T: BitSymmetricDifferenceAssign<U> + BitEmpty + BitTestNone,
+{
+    fn symmetric_difference_assign(&mut self, rhs: &Option<U>) {
+        if let Some(rhs) = rhs {
+            let lhs = self.get_or_insert_with(T::empty);
+            lhs.symmetric_difference_assign(rhs);
+            if lhs.test_none() {
+                *self = None;
+            }
+        }
+    }
+}
+
 impl<T, U> BitSubset<U> for Option<T>
 where
     T: BitSubset<U>,
@@ -181,3 +398,16 @@ where
         }
     }
 }
+
+impl<T> PtrEq for Option<T>
+where
+    T: PtrEq,
+{
+    fn ptr_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (None, None) => true,
+            (Some(lhs), Some(rhs)) => lhs.ptr_eq(rhs),
+            _ => false,
+        }
+    }
+}
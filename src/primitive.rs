@@ -1,4 +1,125 @@
-use crate::{complement::Complement, ops::*};
+use crate::{
+    complement::Complement, difference::Difference, intersection::Intersection, ops::*,
+    symmetric_difference::SymmetricDifference, union::Union,
+};
+
+/// Evaluates to bits that start runs of `len` consecutive bits set in `bits`.
+/// Shifts and combines runs of doubling length, so runs never cross block boundary.
+/// `len` must be in `1..=size`.
+macro_rules! run_starts {
+    ($bits:expr, $len:expr) => {{
+        let (mut starts, len, mut run) = ($bits, $len, 1);
+        while run < len {
+            let shift = crate::min(run, len - run);
+            starts &= starts >> shift;
+            run += shift;
+        }
+        starts
+    }};
+}
+
+/// Returns from enclosing function index of first run of `len` consecutive bits
+/// set in blocks mapped with `$map`, searching block at a time.
+/// Run that crosses block boundary is tracked as bits set at the end of previous blocks.
+macro_rules! find_run_in_blocks {
+    ($blocks:expr, $ty:ty : $size:literal, $len:expr, $lower_bound:expr, $map:expr) => {{
+        let (blocks, len, lower_bound) = ($blocks, $len, $lower_bound);
+        let max = blocks.len() * $size - 1;
+
+        if len == 0 {
+            return Some(lower_bound).filter(|&idx| idx <= max);
+        }
+        if lower_bound > max || len - 1 > max - lower_bound {
+            return None;
+        }
+
+        let first = lower_bound / $size;
+        let mut carry = 0;
+
+        for (i, &block) in blocks.iter().enumerate().skip(first) {
+            let mut bits: $ty = $map(block);
+            if i == first {
+                bits &= <$ty>::MAX << (lower_bound % $size);
+            }
+
+            if carry + bits.trailing_ones() as usize >= len {
+                return Some(i * $size - carry);
+            }
+
+            if len <= $size {
+                let starts = run_starts!(bits, len);
+                if starts != 0 {
+                    return Some(i * $size + starts.trailing_zeros() as usize);
+                }
+            }
+
+            carry = if bits == <$ty>::MAX {
+                carry + $size
+            } else {
+                bits.leading_ones() as usize
+            };
+        }
+
+        None
+    }};
+}
+
+/// Returns from enclosing function index of first block of `2^order` bits unset
+/// aligned to `2^order` in blocks.
+/// Blocks up to the size of primitive are found within each primitive,
+/// larger blocks consist of whole zero primitives.
+macro_rules! find_aligned_in_blocks {
+    ($blocks:expr, $ty:ty : $size:literal, $order:expr) => {{
+        let (blocks, order) = ($blocks, $order);
+        let size = match 1usize.checked_shl(order) {
+            Some(size) => size,
+            None => return None,
+        };
+
+        if size <= $size {
+            // Bits at every multiple of `size`.
+            let mut aligned: $ty = 1;
+            let mut step = size;
+            while step < $size {
+                aligned |= aligned << step;
+                step *= 2;
+            }
+
+            for (i, &block) in blocks.iter().enumerate() {
+                let starts = run_starts!(!block, size) & aligned;
+                if starts != 0 {
+                    return Some(i * $size + starts.trailing_zeros() as usize);
+                }
+            }
+            None
+        } else {
+            blocks
+                .chunks_exact(size / $size)
+                .position(|chunk| chunk.iter().all(|&block| block == 0))
+                .map(|i| i * size)
+        }
+    }};
+}
+
+/// Evaluates to 64 bits from `64 * idx` to `64 * idx + 63` of blocks.
+/// Bits past the last block are unset.
+macro_rules! word64_in_blocks {
+    ($blocks:expr, $ty:ty : $size:literal, $idx:expr) => {{
+        let (blocks, idx): (&[$ty], usize) = (&$blocks[..], $idx);
+        match idx.checked_mul(64) {
+            None => 0,
+            Some(first) if $size >= 64 => blocks
+                .get(first / $size)
+                .map_or(0, |&block| (block >> (first % $size)) as u64),
+            Some(first) => blocks
+                .iter()
+                .skip(first / $size)
+                .take(64 / $size)
+                .enumerate()
+                .fold(0, |word, (i, &block)| word | (block as u64) << (i * $size)),
+        }
+    }};
+}
 
 macro_rules! impl_for_primitive {
     ($ty:ty : $size:literal) => {
@@ -9,6 +130,12 @@ macro_rules! impl_for_primitive {
             }
         }
 
+        impl BitFull for $ty {
+            fn full() -> $ty {
+                <$ty>::MAX
+            }
+        }
+
         impl BitTest for $ty {
             #[inline]
             fn test(&self, idx: usize) -> bool {
@@ -23,7 +150,7 @@ macro_rules! impl_for_primitive {
         impl BitTestAll for $ty {
             #[inline]
             fn test_all(&self) -> bool {
-                $size == usize::MAX && *self == <$ty>::MAX
+                *self == <$ty>::MAX
             }
         }
 
@@ -59,18 +186,148 @@ macro_rules! impl_for_primitive {
             }
         }
 
+        impl BitSetRange for $ty {
+            #[inline]
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                debug_assert!(first <= last && last < $size);
+                *self |= (<$ty>::MAX).wrapping_shl(first as u32)
+                    & (<$ty>::MAX).wrapping_shr((Self::MAX_SET_INDEX - last) as u32);
+            }
+        }
+
+        impl BitUnsetRange for $ty {
+            #[inline]
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                if first < $size {
+                    let last = last.min(Self::MAX_SET_INDEX);
+                    *self &= !((<$ty>::MAX).wrapping_shl(first as u32)
+                        & (<$ty>::MAX).wrapping_shr((Self::MAX_SET_INDEX - last) as u32));
+                }
+            }
+        }
+
+        impl BitTestRange for $ty {
+            #[inline]
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::range_bounds(&range, usize::MAX) {
+                    None => true,
+                    Some((_, last)) if last > Self::MAX_SET_INDEX => false,
+                    Some((first, last)) => {
+                        let mask = (<$ty>::MAX).wrapping_shl(first as u32)
+                            & (<$ty>::MAX).wrapping_shr((Self::MAX_SET_INDEX - last) as u32);
+                        *self & mask == mask
+                    }
+                }
+            }
+
+            #[inline]
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+                    None => false,
+                    Some((first, last)) => {
+                        let mask = (<$ty>::MAX).wrapping_shl(first as u32)
+                            & (<$ty>::MAX).wrapping_shr((Self::MAX_SET_INDEX - last) as u32);
+                        *self & mask != 0
+                    }
+                }
+            }
+        }
+
         impl BitSearch for $ty {
             fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
                 if lower_bound > Self::MAX_SET_INDEX {
                     return None;
                 }
 
-                let masked = *self & (<$ty>::MAX).wrapping_shl((lower_bound as u32));
+                let masked = *self & (<$ty>::MAX).wrapping_shl(lower_bound as u32);
                 match masked.trailing_zeros() {
                     $size => None,
                     idx => Some(idx as usize),
                 }
             }
+
+            #[inline]
+            fn word64(&self, idx: usize) -> Option<u64> {
+                Some(word64_in_blocks!([*self], $ty : $size, idx))
+            }
+        }
+
+        impl BitSearchUnset for $ty {
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                if lower_bound > Self::MAX_SET_INDEX {
+                    return Some(lower_bound);
+                }
+
+                let masked = *self | !(<$ty>::MAX).wrapping_shl(lower_bound as u32);
+                Some(masked.trailing_ones() as usize)
+            }
+        }
+
+        impl BitSearchRun for $ty {
+            fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                find_run_in_blocks!([*self], $ty : $size, len, lower_bound, |block| block)
+            }
+
+            fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                find_run_in_blocks!([*self], $ty : $size, len, lower_bound, |block: $ty| !block)
+            }
+
+            fn find_aligned_free(&self, order: u32) -> Option<usize> {
+                find_aligned_in_blocks!([*self], $ty : $size, order)
+            }
+        }
+
+        impl BitCount for $ty {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+                    None => 0,
+                    Some((start, end)) => {
+                        let mask = (<$ty>::MAX).wrapping_shl(start as u32)
+                            & (<$ty>::MAX).wrapping_shr((Self::MAX_SET_INDEX - end) as u32);
+                        (*self & mask).count_ones() as usize
+                    }
+                }
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                <$ty>::count_ones(*self) as usize
+            }
+        }
+
+        impl BitRankSelect for $ty {
+            fn select(&self, n: usize) -> Option<usize> {
+                if n >= BitCount::count_ones(self) {
+                    return None;
+                }
+
+                let mut bits = *self;
+                for _ in 0..n {
+                    bits &= bits - 1;
+                }
+                Some(bits.trailing_zeros() as usize)
+            }
+        }
+
+        impl BitSearchRev for $ty {
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                let upper_bound = upper_bound.min(Self::MAX_SET_INDEX);
+
+                let masked = *self & (<$ty>::MAX).wrapping_shr((Self::MAX_SET_INDEX - upper_bound) as u32);
+                match masked.leading_zeros() {
+                    $size => None,
+                    zeros => Some(Self::MAX_SET_INDEX - zeros as usize),
+                }
+            }
         }
 
         impl BitComplement for $ty {
@@ -129,6 +386,66 @@ macro_rules! impl_for_primitive {
             }
         }
 
+        impl BitSymmetricDifference for $ty {
+            type Output = Self;
+
+            fn symmetric_difference(self, rhs: Self) -> Self {
+                self ^ rhs
+            }
+        }
+
+        impl BitSymmetricDifference<Complement<$ty>> for $ty {
+            type Output = Complement<Self>;
+
+            fn symmetric_difference(self, rhs: Complement<Self>) -> Complement<Self> {
+                Complement(self ^ rhs.0)
+            }
+        }
+
+        impl BitUnionAssign for $ty {
+            #[inline]
+            fn union_assign(&mut self, rhs: &Self) {
+                *self |= *rhs;
+            }
+        }
+
+        impl BitIntersectionAssign for $ty {
+            #[inline]
+            fn intersect_assign(&mut self, rhs: &Self) {
+                *self &= *rhs;
+            }
+        }
+
+        impl BitIntersectionAssign<Complement<$ty>> for $ty {
+            #[inline]
+            fn intersect_assign(&mut self, rhs: &Complement<Self>) {
+                *self &= !rhs.0;
+            }
+        }
+
+        impl BitDifferenceAssign for $ty {
+            #[inline]
+            fn difference_assign(&mut self, rhs: &Self) {
+                *self &= !*rhs;
+            }
+        }
+
+        impl BitDifferenceAssign<Complement<$ty>> for $ty {
+            #[inline]
+            fn difference_assign(&mut self, rhs: &Complement<Self>) {
+                *self &= rhs.0;
+            }
+        }
+
+        impl BitSymmetricDifferenceAssign for $ty {
+            #[inline]
+            fn symmetric_difference_assign(&mut self, rhs: &Self) {
+                *self ^= *rhs;
+            }
+        }
+
+        impl_assign_by_search!(impl[] for $ty; Union Intersection Difference SymmetricDifference);
+
         impl BitSubset for $ty {
             fn is_subset_of(&self, rhs: &Self) -> bool {
                 *self & !*rhs == 0
@@ -160,6 +477,12 @@ macro_rules! impl_for_primitive {
             }
         }
 
+        impl<const N: usize> BitFull for [$ty; N] {
+            fn full() -> [$ty; N] {
+                [<$ty>::MAX; N]
+            }
+        }
+
         impl<const N: usize> BitTest for [$ty; N] {
             #[inline]
             fn test(&self, idx: usize) -> bool {
@@ -176,7 +499,7 @@ macro_rules! impl_for_primitive {
         impl<const N: usize> BitTestAll for [$ty; N] {
             #[inline]
             fn test_all(&self) -> bool {
-                false
+                self.iter().all(|e| *e == <$ty>::MAX)
             }
         }
 
@@ -217,6 +540,75 @@ macro_rules! impl_for_primitive {
             }
         }
 
+        impl<const N: usize> BitSetRange for [$ty; N] {
+            #[inline]
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                debug_assert!(first <= last && last < $size * N);
+                let (i_first, i_last) = (first / $size, last / $size);
+
+                for i in i_first..=i_last {
+                    let lower = if i == i_first { first % $size } else { 0 };
+                    let upper = if i == i_last { last % $size } else { $size - 1 };
+                    self[i].set_range_unchecked(lower, upper);
+                }
+            }
+        }
+
+        impl<const N: usize> BitUnsetRange for [$ty; N] {
+            #[inline]
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                if first <= Self::MAX_SET_INDEX {
+                    let last = last.min(Self::MAX_SET_INDEX);
+                    let (i_first, i_last) = (first / $size, last / $size);
+
+                    for i in i_first..=i_last {
+                        let lower = if i == i_first { first % $size } else { 0 };
+                        let upper = if i == i_last { last % $size } else { $size - 1 };
+                        self[i].unset_range_unchecked(lower, upper);
+                    }
+                }
+            }
+        }
+
+        impl<const N: usize> BitTestRange for [$ty; N] {
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::range_bounds(&range, usize::MAX) {
+                    None => true,
+                    Some((_, last)) if last > Self::MAX_SET_INDEX => false,
+                    Some((first, last)) => {
+                        let (i_first, i_last) = (first / $size, last / $size);
+
+                        (i_first..=i_last).all(|i| {
+                            let lower = if i == i_first { first % $size } else { 0 };
+                            let upper = if i == i_last { last % $size } else { $size - 1 };
+                            self[i].test_range_all(lower..=upper)
+                        })
+                    }
+                }
+            }
+
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+                    None => false,
+                    Some((first, last)) => {
+                        let (i_first, i_last) = (first / $size, last / $size);
+
+                        (i_first..=i_last).any(|i| {
+                            let lower = if i == i_first { first % $size } else { 0 };
+                            let upper = if i == i_last { last % $size } else { $size - 1 };
+                            self[i].test_range_any(lower..=upper)
+                        })
+                    }
+                }
+            }
+        }
+
         impl<const N: usize> BitSearch for [$ty; N] {
             fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
                 if lower_bound > Self::MAX_SET_INDEX {
@@ -226,7 +618,7 @@ macro_rules! impl_for_primitive {
                 let mut i = lower_bound / $size;
                 let j = lower_bound % $size;
 
-                let mut masked = self[i] & (<$ty>::MAX).wrapping_shl((j as u32));
+                let mut masked = self[i] & (<$ty>::MAX).wrapping_shl(j as u32);
 
                 loop {
                     match masked.trailing_zeros() {
@@ -241,6 +633,116 @@ macro_rules! impl_for_primitive {
                     }
                 }
             }
+
+            #[inline]
+            fn word64(&self, idx: usize) -> Option<u64> {
+                Some(word64_in_blocks!(self, $ty : $size, idx))
+            }
+        }
+
+        impl<const N: usize> BitSearchUnset for [$ty; N] {
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                if lower_bound > Self::MAX_SET_INDEX {
+                    return Some(lower_bound);
+                }
+
+                let mut i = lower_bound / $size;
+                let j = lower_bound % $size;
+
+                let mut masked = self[i] | !(<$ty>::MAX).wrapping_shl(j as u32);
+
+                loop {
+                    match masked.trailing_ones() {
+                        $size => {
+                            i += 1;
+                            if i >= N {
+                                return Some(Self::MAX_SET_INDEX + 1);
+                            }
+                            masked = self[i];
+                        },
+                        idx => return Some(i * $size + idx as usize)
+                    }
+                }
+            }
+        }
+
+        impl<const N: usize> BitSearchRun for [$ty; N] {
+            fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                find_run_in_blocks!(self, $ty : $size, len, lower_bound, |block| block)
+            }
+
+            fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                find_run_in_blocks!(self, $ty : $size, len, lower_bound, |block: $ty| !block)
+            }
+
+            fn find_aligned_free(&self, order: u32) -> Option<usize> {
+                find_aligned_in_blocks!(self, $ty : $size, order)
+            }
+        }
+
+        impl<const N: usize> BitCount for [$ty; N] {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                let (start, end) = match crate::inclusive_bounds(&range, Self::MAX_SET_INDEX) {
+                    None => return 0,
+                    Some(bounds) => bounds,
+                };
+
+                let first = start / $size;
+                let last = end / $size;
+
+                (first..=last)
+                    .map(|i| {
+                        let lower = if i == first { start % $size } else { 0 };
+                        let upper = if i == last { end % $size } else { $size - 1 };
+                        self[i].count_in_range(lower..=upper)
+                    })
+                    .sum()
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                self.iter().map(|e| e.count_ones() as usize).sum()
+            }
+        }
+
+        impl<const N: usize> BitRankSelect for [$ty; N] {
+            fn select(&self, mut n: usize) -> Option<usize> {
+                for (i, e) in self.iter().enumerate() {
+                    let count = e.count_ones() as usize;
+                    if n < count {
+                        return Some(i * $size + e.select(n)?);
+                    }
+                    n -= count;
+                }
+                None
+            }
+        }
+
+        impl<const N: usize> BitSearchRev for [$ty; N] {
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                let upper_bound = upper_bound.min(Self::MAX_SET_INDEX);
+
+                let mut i = upper_bound / $size;
+                let j = upper_bound % $size;
+
+                let mut masked = self[i] & (<$ty>::MAX).wrapping_shr(($size - 1 - j) as u32);
+
+                loop {
+                    match masked.leading_zeros() {
+                        $size => {
+                            if i == 0 {
+                                return None;
+                            }
+                            i -= 1;
+                            masked = self[i];
+                        },
+                        zeros => return Some(i * $size + $size - 1 - zeros as usize)
+                    }
+                }
+            }
         }
 
         impl<const N: usize> BitComplement for [$ty; N] {
@@ -299,6 +801,66 @@ macro_rules! impl_for_primitive {
             }
         }
 
+        impl<const N: usize> BitSymmetricDifference for [$ty; N] {
+            type Output = Self;
+
+            fn symmetric_difference(self, rhs: Self) -> Self {
+                crate::map2_arrays(self, rhs, |l, r| l ^ r)
+            }
+        }
+
+        impl<const N: usize> BitSymmetricDifference<Complement<[$ty; N]>> for [$ty; N] {
+            type Output = Complement<Self>;
+
+            fn symmetric_difference(self, rhs: Complement<Self>) -> Complement<Self> {
+                Complement(crate::map2_arrays(self, rhs.0, |l, r| l ^ r))
+            }
+        }
+
+        impl<const N: usize> BitUnionAssign for [$ty; N] {
+            #[inline]
+            fn union_assign(&mut self, rhs: &Self) {
+                self.iter_mut().zip(rhs).for_each(|(lhs, rhs)| *lhs |= *rhs);
+            }
+        }
+
+        impl<const N: usize> BitIntersectionAssign for [$ty; N] {
+            #[inline]
+            fn intersect_assign(&mut self, rhs: &Self) {
+                self.iter_mut().zip(rhs).for_each(|(lhs, rhs)| *lhs &= *rhs);
+            }
+        }
+
+        impl<const N: usize> BitIntersectionAssign<Complement<[$ty; N]>> for [$ty; N] {
+            #[inline]
+            fn intersect_assign(&mut self, rhs: &Complement<Self>) {
+                self.iter_mut().zip(&rhs.0).for_each(|(lhs, rhs)| *lhs &= !*rhs);
+            }
+        }
+
+        impl<const N: usize> BitDifferenceAssign for [$ty; N] {
+            #[inline]
+            fn difference_assign(&mut self, rhs: &Self) {
+                self.iter_mut().zip(rhs).for_each(|(lhs, rhs)| *lhs &= !*rhs);
+            }
+        }
+
+        impl<const N: usize> BitDifferenceAssign<Complement<[$ty; N]>> for [$ty; N] {
+            #[inline]
+            fn difference_assign(&mut self, rhs: &Complement<Self>) {
+                self.iter_mut().zip(&rhs.0).for_each(|(lhs, rhs)| *lhs &= *rhs);
+            }
+        }
+
+        impl<const N: usize> BitSymmetricDifferenceAssign for [$ty; N] {
+            #[inline]
+            fn symmetric_difference_assign(&mut self, rhs: &Self) {
+                self.iter_mut().zip(rhs).for_each(|(lhs, rhs)| *lhs ^= *rhs);
+            }
+        }
+
+        impl_assign_by_search!(impl[const N: usize] for [$ty; N]; Union Intersection Difference SymmetricDifference);
+
         impl<const N: usize> BitSubset for [$ty; N] {
             fn is_subset_of(&self, rhs: &Self) -> bool {
                 self.iter().zip(rhs).all(|(lhs, rhs)| *lhs & !*rhs == 0)
@@ -338,6 +900,12 @@ impl BitEmpty for bool {
     }
 }
 
+impl BitFull for bool {
+    fn full() -> bool {
+        true
+    }
+}
+
 impl BitTest for bool {
     #[inline]
     fn test(&self, idx: usize) -> bool {
@@ -352,7 +920,7 @@ impl BitTest for bool {
 impl BitTestAll for bool {
     #[inline]
     fn test_all(&self) -> bool {
-        usize::MAX == 0 && *self
+        *self
     }
 }
 
@@ -388,6 +956,44 @@ impl BitUnset for bool {
     }
 }
 
+impl BitSetRange for bool {
+    #[inline]
+    unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+        debug_assert!(first == 0 && last == 0);
+        *self = true;
+    }
+}
+
+impl BitUnsetRange for bool {
+    #[inline]
+    unsafe fn unset_range_unchecked(&mut self, first: usize, _last: usize) {
+        if first == 0 {
+            *self = false;
+        }
+    }
+}
+
+impl BitTestRange for bool {
+    #[inline]
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::range_bounds(&range, usize::MAX) {
+            None => true,
+            Some((first, last)) => *self && first == 0 && last == 0,
+        }
+    }
+
+    #[inline]
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        *self && range.contains(&0)
+    }
+}
+
 impl BitSearch for bool {
     fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
         if lower_bound > 0 {
@@ -400,6 +1006,56 @@ impl BitSearch for bool {
             None
         }
     }
+
+    #[inline]
+    fn word64(&self, idx: usize) -> Option<u64> {
+        Some(if idx == 0 { *self as u64 } else { 0 })
+    }
+}
+
+impl BitSearchUnset for bool {
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        if lower_bound == 0 && *self {
+            Some(1)
+        } else {
+            Some(lower_bound)
+        }
+    }
+}
+
+impl BitSearchRun for bool {}
+
+impl BitCount for bool {
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        if *self && range.contains(&0) {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+impl BitRankSelect for bool {
+    fn select(&self, n: usize) -> Option<usize> {
+        if *self && n == 0 {
+            Some(0)
+        } else {
+            None
+        }
+    }
+}
+
+impl BitSearchRev for bool {
+    fn find_last_set(&self, _upper_bound: usize) -> Option<usize> {
+        if *self {
+            Some(0)
+        } else {
+            None
+        }
+    }
 }
 
 impl BitComplement for bool {
@@ -458,6 +1114,66 @@ impl BitDifference<Complement<bool>> for bool {
     }
 }
 
+impl BitSymmetricDifference for bool {
+    type Output = Self;
+
+    fn symmetric_difference(self, rhs: Self) -> Self {
+        self != rhs
+    }
+}
+
+impl BitSymmetricDifference<Complement<bool>> for bool {
+    type Output = Complement<Self>;
+
+    fn symmetric_difference(self, rhs: Complement<Self>) -> Complement<Self> {
+        Complement(self != rhs.0)
+    }
+}
+
+impl BitUnionAssign for bool {
+    #[inline]
+    fn union_assign(&mut self, rhs: &Self) {
+        *self |= *rhs;
+    }
+}
+
+impl BitIntersectionAssign for bool {
+    #[inline]
+    fn intersect_assign(&mut self, rhs: &Self) {
+        *self &= *rhs;
+    }
+}
+
+impl BitIntersectionAssign<Complement<bool>> for bool {
+    #[inline]
+    fn intersect_assign(&mut self, rhs: &Complement<Self>) {
+        *self &= !rhs.0;
+    }
+}
+
+impl BitDifferenceAssign for bool {
+    #[inline]
+    fn difference_assign(&mut self, rhs: &Self) {
+        *self &= !*rhs;
+    }
+}
+
+impl BitDifferenceAssign<Complement<bool>> for bool {
+    #[inline]
+    fn difference_assign(&mut self, rhs: &Complement<Self>) {
+        *self &= rhs.0;
+    }
+}
+
+impl BitSymmetricDifferenceAssign for bool {
+    #[inline]
+    fn symmetric_difference_assign(&mut self, rhs: &Self) {
+        *self ^= *rhs;
+    }
+}
+
+impl_assign_by_search!(impl[] for bool; Union Intersection Difference SymmetricDifference);
+
 impl BitSubset for bool {
     fn is_subset_of(&self, rhs: &Self) -> bool {
         !*self || *rhs
@@ -481,3 +1197,25 @@ impl BitDisjoint<Complement<bool>> for bool {
         !*self || rhs.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::{check_rank_select, check_runs, Rng};
+
+    #[test]
+    fn runs_across_words_match_model() {
+        let mut rng = Rng::new(24);
+        check_runs::<u64>(&mut rng, 10);
+        check_runs::<[u8; 16]>(&mut rng, 20);
+        check_runs::<[u64; 8]>(&mut rng, 150);
+    }
+
+    #[test]
+    fn rank_select_match_model() {
+        let mut rng = Rng::new(5);
+        check_rank_select::<u8>(&mut rng, 3);
+        check_rank_select::<u128>(&mut rng, 10);
+        check_rank_select::<[u16; 4]>(&mut rng, 10);
+        check_rank_select::<[u64; 8]>(&mut rng, 100);
+    }
+}
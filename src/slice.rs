@@ -0,0 +1,760 @@
+use crate::{
+    difference::Difference, intersection::Intersection, ops::*,
+    symmetric_difference::SymmetricDifference, union::Union,
+};
+
+#[cfg(feature = "alloc")]
+use {crate::complement::Complement, alloc::vec::Vec};
+
+/// Implements bit-set traits for slices and vectors of primitive blocks.
+///
+/// Bit layout is the same as for arrays of blocks.
+/// Slices are bound by their length at runtime, thus `MAX_SET_INDEX` is `usize::MAX`
+/// and setting bits past the end of the slice panics.
+/// Bits past the end are unset, so searches of unset bits may return indices past the end.
+/// `IdAllocator::from_bits_with_max_id` bounds allocation by the slice length.
+/// Vectors grow to hold bits being set.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let mut buffer = [0u64; 4];
+/// let slice = &mut buffer[..];
+/// slice.set(3);
+/// slice.set_range(100..=130);
+/// assert_eq!(slice.find_first_set(4), Some(100));
+/// assert_eq!(buffer, {
+///     let mut array = [0u64; 4];
+///     array.set(3);
+///     array.set_range(100..=130);
+///     array
+/// });
+///
+/// let mut full = [u64::MAX];
+/// assert_eq!(full[..].find_first_unset(0), Some(64));
+/// assert!(Complement(&full[..]).test(64));
+/// assert_eq!(Complement(&full[..]).find_first_set(64), Some(64));
+/// assert_eq!(full[..].find_first_run_unset(10, 0), Some(64));
+/// assert_eq!(full[..].find_aligned_free(3), Some(64));
+/// let mut ids = IdAllocator::from_bits_with_max_id(&mut full[..], 63);
+/// assert_eq!(ids.alloc(), Err(CapacityExceeded));
+///
+/// let mut vec = Vec::<u32>::empty();
+/// vec.set(1000);
+/// assert_eq!(vec.len(), 32);
+/// vec.union_assign(&[0b101u32]);
+/// assert!(vec.iter_set().eq([0, 2, 1000]));
+/// assert_eq!(vec.find_first_run_unset(100, 990), Some(1001));
+/// ```
+macro_rules! impl_for_slice {
+    ($ty:ty : $size:literal) => {
+        impl BitTest for [$ty] {
+            #[inline]
+            fn test(&self, idx: usize) -> bool {
+                match self.get(idx / $size) {
+                    Some(e) => 0 != e & ((1 as $ty) << (idx % $size)),
+                    None => false,
+                }
+            }
+        }
+
+        impl BitTestNone for [$ty] {
+            #[inline]
+            fn test_none(&self) -> bool {
+                self.iter().all(|e| *e == 0)
+            }
+        }
+
+        impl BitSetLimit for [$ty] {
+            const MAX_SET_INDEX: usize = usize::MAX;
+        }
+
+        impl BitSet for [$ty] {
+            #[inline]
+            unsafe fn set_unchecked(&mut self, idx: usize) {
+                // Length is known only at runtime.
+                assert!(idx / $size < self.len(), "Idx out of bounds");
+                self[idx / $size] |= (1 as $ty) << (idx % $size);
+            }
+        }
+
+        impl BitUnsetLimit for [$ty] {
+            const MAX_UNSET_INDEX: usize = usize::MAX;
+        }
+
+        impl BitUnset for [$ty] {
+            #[inline]
+            unsafe fn unset_unchecked(&mut self, idx: usize) {
+                if let Some(e) = self.get_mut(idx / $size) {
+                    *e &= !((1 as $ty) << (idx % $size));
+                }
+            }
+        }
+
+        impl BitSetRange for [$ty] {
+            #[inline]
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                debug_assert!(first <= last);
+                // Length is known only at runtime.
+                assert!(last / $size < self.len(), "Idx out of bounds");
+                let (i_first, i_last) = (first / $size, last / $size);
+
+                for i in i_first..=i_last {
+                    let lower = if i == i_first { first % $size } else { 0 };
+                    let upper = if i == i_last { last % $size } else { $size - 1 };
+                    self[i].set_range_unchecked(lower, upper);
+                }
+            }
+        }
+
+        impl BitUnsetRange for [$ty] {
+            #[inline]
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                if first / $size < self.len() {
+                    let last = last.min(self.len() * $size - 1);
+                    let (i_first, i_last) = (first / $size, last / $size);
+
+                    for i in i_first..=i_last {
+                        let lower = if i == i_first { first % $size } else { 0 };
+                        let upper = if i == i_last { last % $size } else { $size - 1 };
+                        self[i].unset_range_unchecked(lower, upper);
+                    }
+                }
+            }
+        }
+
+        impl BitTestRange for [$ty] {
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                match crate::range_bounds(&range, usize::MAX) {
+                    None => true,
+                    Some((_, last)) if last / $size >= self.len() => false,
+                    Some((first, last)) => {
+                        let (i_first, i_last) = (first / $size, last / $size);
+
+                        (i_first..=i_last).all(|i| {
+                            let lower = if i == i_first { first % $size } else { 0 };
+                            let upper = if i == i_last { last % $size } else { $size - 1 };
+                            self[i].test_range_all(lower..=upper)
+                        })
+                    }
+                }
+            }
+
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                if self.is_empty() {
+                    return false;
+                }
+
+                match crate::inclusive_bounds(&range, self.len() * $size - 1) {
+                    None => false,
+                    Some((first, last)) => {
+                        let (i_first, i_last) = (first / $size, last / $size);
+
+                        (i_first..=i_last).any(|i| {
+                            let lower = if i == i_first { first % $size } else { 0 };
+                            let upper = if i == i_last { last % $size } else { $size - 1 };
+                            self[i].test_range_any(lower..=upper)
+                        })
+                    }
+                }
+            }
+        }
+
+        impl BitSearch for [$ty] {
+            fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+                let mut i = lower_bound / $size;
+                let j = lower_bound % $size;
+
+                let mut masked = *self.get(i)? & (<$ty>::MAX).wrapping_shl(j as u32);
+
+                loop {
+                    match masked.trailing_zeros() {
+                        $size => {
+                            i += 1;
+                            masked = *self.get(i)?;
+                        },
+                        idx => return Some(i * $size + idx as usize)
+                    }
+                }
+            }
+
+            #[inline]
+            fn word64(&self, idx: usize) -> Option<u64> {
+                Some(word64_in_blocks!(self, $ty : $size, idx))
+            }
+        }
+
+        impl BitSearchUnset for [$ty] {
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                let mut i = lower_bound / $size;
+                let j = lower_bound % $size;
+
+                // Bits past the end are unset.
+                let mut masked = match self.get(i) {
+                    Some(e) => *e | !(<$ty>::MAX).wrapping_shl(j as u32),
+                    None => return Some(lower_bound),
+                };
+
+                loop {
+                    match masked.trailing_ones() {
+                        $size => {
+                            i += 1;
+                            masked = match self.get(i) {
+                                Some(e) => *e,
+                                None => return Some(i * $size),
+                            };
+                        },
+                        idx => return Some(i * $size + idx as usize)
+                    }
+                }
+            }
+        }
+
+        impl BitSearchRun for [$ty] {
+            fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                if len == 0 {
+                    return Some(lower_bound);
+                }
+                if self.is_empty() {
+                    return None;
+                }
+                find_run_in_blocks!(self, $ty : $size, len, lower_bound, |block| block)
+            }
+
+            fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                fn find_in_blocks(blocks: &[$ty], len: usize, lower_bound: usize) -> Option<usize> {
+                    if blocks.is_empty() {
+                        return None;
+                    }
+                    find_run_in_blocks!(blocks, $ty : $size, len, lower_bound, |block: $ty| !block)
+                }
+
+                if len == 0 {
+                    return Some(lower_bound);
+                }
+                find_in_blocks(self, len, lower_bound).or_else(|| {
+                    // Run may continue past the end, after the last bit set.
+                    let start = lower_bound.max(self.find_last_set(usize::MAX).map_or(0, |idx| idx + 1));
+                    start.checked_add(len - 1)?;
+                    Some(start)
+                })
+            }
+
+            fn find_aligned_free(&self, order: u32) -> Option<usize> {
+                fn find_in_blocks(blocks: &[$ty], order: u32) -> Option<usize> {
+                    find_aligned_in_blocks!(blocks, $ty : $size, order)
+                }
+
+                find_in_blocks(self, order).or_else(|| {
+                    // Block may continue past the end, after the last bit set.
+                    let size = 1usize.checked_shl(order)?;
+                    let start = self.find_last_set(usize::MAX).map_or(0, |idx| idx + 1);
+                    let aligned = start.checked_add(size - 1)? & !(size - 1);
+                    aligned.checked_add(size - 1)?;
+                    Some(aligned)
+                })
+            }
+        }
+
+        impl BitCount for [$ty] {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                if self.is_empty() {
+                    return 0;
+                }
+
+                let (start, end) = match crate::inclusive_bounds(&range, self.len() * $size - 1) {
+                    None => return 0,
+                    Some(bounds) => bounds,
+                };
+
+                let first = start / $size;
+                let last = end / $size;
+
+                (first..=last)
+                    .map(|i| {
+                        let lower = if i == first { start % $size } else { 0 };
+                        let upper = if i == last { end % $size } else { $size - 1 };
+                        self[i].count_in_range(lower..=upper)
+                    })
+                    .sum()
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                self.iter().map(|e| e.count_ones() as usize).sum()
+            }
+        }
+
+        impl BitRankSelect for [$ty] {
+            fn select(&self, mut n: usize) -> Option<usize> {
+                for (i, e) in self.iter().enumerate() {
+                    let count = e.count_ones() as usize;
+                    if n < count {
+                        return Some(i * $size + e.select(n)?);
+                    }
+                    n -= count;
+                }
+                None
+            }
+        }
+
+        impl BitSearchRev for [$ty] {
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                if self.is_empty() {
+                    return None;
+                }
+
+                let upper_bound = upper_bound.min(self.len() * $size - 1);
+
+                let mut i = upper_bound / $size;
+                let j = upper_bound % $size;
+
+                let mut masked = self[i] & (<$ty>::MAX).wrapping_shr(($size - 1 - j) as u32);
+
+                loop {
+                    match masked.leading_zeros() {
+                        $size => {
+                            if i == 0 {
+                                return None;
+                            }
+                            i -= 1;
+                            masked = self[i];
+                        },
+                        zeros => return Some(i * $size + $size - 1 - zeros as usize)
+                    }
+                }
+            }
+        }
+
+        impl_assign_by_search!(impl[] for [$ty]; Union Intersection Difference SymmetricDifference);
+
+        impl_for_slice!(@rhs $ty [const N: usize] [$ty; N]);
+
+        #[cfg(feature = "alloc")]
+        impl_for_slice!(@rhs $ty [] Vec<$ty>);
+
+        #[cfg(feature = "alloc")]
+        impl BitEmpty for Vec<$ty> {
+            fn empty() -> Vec<$ty> {
+                Vec::new()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitTest for Vec<$ty> {
+            #[inline]
+            fn test(&self, idx: usize) -> bool {
+                <[$ty]>::test(self, idx)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitTestNone for Vec<$ty> {
+            #[inline]
+            fn test_none(&self) -> bool {
+                <[$ty]>::test_none(self)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSetLimit for Vec<$ty> {
+            const MAX_SET_INDEX: usize = usize::MAX;
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSet for Vec<$ty> {
+            #[inline]
+            unsafe fn set_unchecked(&mut self, idx: usize) {
+                if idx / $size >= self.len() {
+                    self.resize(idx / $size + 1, 0);
+                }
+                <[$ty]>::set_unchecked(self, idx)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitUnsetLimit for Vec<$ty> {
+            const MAX_UNSET_INDEX: usize = usize::MAX;
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitUnset for Vec<$ty> {
+            #[inline]
+            unsafe fn unset_unchecked(&mut self, idx: usize) {
+                <[$ty]>::unset_unchecked(self, idx)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSetRange for Vec<$ty> {
+            #[inline]
+            unsafe fn set_range_unchecked(&mut self, first: usize, last: usize) {
+                if last / $size >= self.len() {
+                    self.resize(last / $size + 1, 0);
+                }
+                <[$ty]>::set_range_unchecked(self, first, last)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitUnsetRange for Vec<$ty> {
+            #[inline]
+            unsafe fn unset_range_unchecked(&mut self, first: usize, last: usize) {
+                <[$ty]>::unset_range_unchecked(self, first, last)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitTestRange for Vec<$ty> {
+            fn test_range_all<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                <[$ty]>::test_range_all(self, range)
+            }
+
+            fn test_range_any<R>(&self, range: R) -> bool
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                <[$ty]>::test_range_any(self, range)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSearch for Vec<$ty> {
+            fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+                <[$ty]>::find_first_set(self, lower_bound)
+            }
+
+            #[inline]
+            fn word64(&self, idx: usize) -> Option<u64> {
+                <[$ty]>::word64(self, idx)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSearchUnset for Vec<$ty> {
+            fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+                <[$ty]>::find_first_unset(self, lower_bound)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSearchRun for Vec<$ty> {
+            fn find_first_run_set(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                <[$ty]>::find_first_run_set(self, len, lower_bound)
+            }
+
+            fn find_first_run_unset(&self, len: usize, lower_bound: usize) -> Option<usize> {
+                <[$ty]>::find_first_run_unset(self, len, lower_bound)
+            }
+
+            fn find_aligned_free(&self, order: u32) -> Option<usize> {
+                <[$ty]>::find_aligned_free(self, order)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitSearchRev for Vec<$ty> {
+            fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+                <[$ty]>::find_last_set(self, upper_bound)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitCount for Vec<$ty> {
+            fn count_in_range<R>(&self, range: R) -> usize
+            where
+                R: core::ops::RangeBounds<usize>,
+            {
+                <[$ty]>::count_in_range(self, range)
+            }
+
+            #[inline]
+            fn count_ones(&self) -> usize {
+                <[$ty]>::count_ones(self)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitRankSelect for Vec<$ty> {
+            fn select(&self, n: usize) -> Option<usize> {
+                <[$ty]>::select(self, n)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl BitComplement for Vec<$ty> {
+            type Output = Complement<Self>;
+
+            fn complement(self) -> Complement<Self> {
+                Complement(self)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<R> BitUnion<R> for Vec<$ty>
+        where
+            Self: BitUnionAssign<R>,
+        {
+            type Output = Self;
+
+            fn union(mut self, rhs: R) -> Self {
+                self.union_assign(&rhs);
+                self
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<R> BitIntersection<R> for Vec<$ty>
+        where
+            Self: BitIntersectionAssign<R>,
+        {
+            type Output = Self;
+
+            fn intersection(mut self, rhs: R) -> Self {
+                self.intersect_assign(&rhs);
+                self
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<R> BitDifference<R> for Vec<$ty>
+        where
+            Self: BitDifferenceAssign<R>,
+        {
+            type Output = Self;
+
+            fn difference(mut self, rhs: R) -> Self {
+                self.difference_assign(&rhs);
+                self
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<R> BitSymmetricDifference<R> for Vec<$ty>
+        where
+            Self: BitSymmetricDifferenceAssign<R>,
+        {
+            type Output = Self;
+
+            fn symmetric_difference(mut self, rhs: R) -> Self {
+                self.symmetric_difference_assign(&rhs);
+                self
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl_assign_by_search!(impl[] for Vec<$ty>; Union Intersection Difference SymmetricDifference);
+
+        #[cfg(feature = "alloc")]
+        impl_for_slice!(@vec_rhs $ty [const N: usize] [$ty; N]);
+
+        #[cfg(feature = "alloc")]
+        impl_for_slice!(@vec_rhs $ty [] Vec<$ty>);
+    };
+
+    // In-place operations for slice with blocks on the right hand side.
+    (@rhs $ty:ty [$($generics:tt)*] $rhs:ty) => {
+        impl<$($generics)*> BitUnionAssign<$rhs> for [$ty] {
+            fn union_assign(&mut self, rhs: &$rhs) {
+                let len = self.len().min(rhs.len());
+                assert!(rhs[len..].iter().all(|e| *e == 0), "Bit-set capacity exceeded");
+                self.iter_mut().zip(rhs.iter()).for_each(|(e, r)| *e |= *r);
+            }
+        }
+
+        impl<$($generics)*> BitIntersectionAssign<$rhs> for [$ty] {
+            fn intersect_assign(&mut self, rhs: &$rhs) {
+                let len = self.len().min(rhs.len());
+                self[len..].iter_mut().for_each(|e| *e = 0);
+                self.iter_mut().zip(rhs.iter()).for_each(|(e, r)| *e &= *r);
+            }
+        }
+
+        impl<$($generics)*> BitDifferenceAssign<$rhs> for [$ty] {
+            fn difference_assign(&mut self, rhs: &$rhs) {
+                self.iter_mut().zip(rhs.iter()).for_each(|(e, r)| *e &= !*r);
+            }
+        }
+
+        impl<$($generics)*> BitSymmetricDifferenceAssign<$rhs> for [$ty] {
+            fn symmetric_difference_assign(&mut self, rhs: &$rhs) {
+                let len = self.len().min(rhs.len());
+                assert!(rhs[len..].iter().all(|e| *e == 0), "Bit-set capacity exceeded");
+                self.iter_mut().zip(rhs.iter()).for_each(|(e, r)| *e ^= *r);
+            }
+        }
+
+        impl<$($generics)*> BitSubset<$rhs> for [$ty] {
+            fn is_subset_of(&self, rhs: &$rhs) -> bool {
+                let len = self.len().min(rhs.len());
+                self[len..].iter().all(|e| *e == 0)
+                    && self.iter().zip(rhs.iter()).all(|(e, r)| *e & !*r == 0)
+            }
+        }
+
+        impl<$($generics)*> BitDisjoint<$rhs> for [$ty] {
+            fn is_disjoint(&self, rhs: &$rhs) -> bool {
+                self.iter().zip(rhs.iter()).all(|(e, r)| *e & *r == 0)
+            }
+        }
+    };
+
+    // In-place operations for vector with blocks on the right hand side.
+    // Vector grows to hold bits set on the right hand side.
+    (@vec_rhs $ty:ty [$($generics:tt)*] $rhs:ty) => {
+        impl<$($generics)*> BitUnionAssign<$rhs> for Vec<$ty> {
+            fn union_assign(&mut self, rhs: &$rhs) {
+                let used = rhs.iter().rposition(|e| *e != 0).map_or(0, |i| i + 1);
+                if self.len() < used {
+                    self.resize(used, 0);
+                }
+                <[$ty]>::union_assign(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> BitIntersectionAssign<$rhs> for Vec<$ty> {
+            fn intersect_assign(&mut self, rhs: &$rhs) {
+                <[$ty]>::intersect_assign(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> BitDifferenceAssign<$rhs> for Vec<$ty> {
+            fn difference_assign(&mut self, rhs: &$rhs) {
+                <[$ty]>::difference_assign(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> BitSymmetricDifferenceAssign<$rhs> for Vec<$ty> {
+            fn symmetric_difference_assign(&mut self, rhs: &$rhs) {
+                let used = rhs.iter().rposition(|e| *e != 0).map_or(0, |i| i + 1);
+                if self.len() < used {
+                    self.resize(used, 0);
+                }
+                <[$ty]>::symmetric_difference_assign(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> BitSubset<$rhs> for Vec<$ty> {
+            fn is_subset_of(&self, rhs: &$rhs) -> bool {
+                <[$ty]>::is_subset_of(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> BitDisjoint<$rhs> for Vec<$ty> {
+            fn is_disjoint(&self, rhs: &$rhs) -> bool {
+                <[$ty]>::is_disjoint(self, rhs)
+            }
+        }
+    };
+
+    ($ty:ty : $size:literal, $($tail:tt)+) => {
+        impl_for_slice!($ty : $size);
+        impl_for_slice!($($tail)+);
+    };
+}
+
+impl_for_slice!(u8 : 8, u16 : 16, u32 : 32, u64 : 64, u128 : 128);
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{complement::Complement, id_alloc::IdAllocator, ops::*, test_util::Rng},
+        core::iter::repeat_with,
+    };
+
+    /// Fills `blocks` with random bits and returns model with some bits past the end.
+    fn random_blocks(rng: &mut Rng, blocks: &mut [u16]) -> [bool; 128] {
+        let density = rng.below(4);
+        let mut model = [false; 128];
+        for (idx, bit) in model.iter_mut().enumerate().take(blocks.len() * 16) {
+            if rng.below(4) < density {
+                blocks.set(idx);
+                *bit = true;
+            }
+        }
+        model
+    }
+
+    #[test]
+    fn searches_match_model() {
+        let mut rng = Rng::new(17);
+        for _ in 0..500 {
+            let mut blocks = [0u16; 5];
+            let n = rng.below(6);
+            let model = random_blocks(&mut rng, &mut blocks[..n]);
+            let slice = &blocks[..n];
+            let unset_from = |lb: usize| (lb..).find(|&i| !model.get(i).copied().unwrap_or(false));
+
+            for _ in 0..20 {
+                let lb = rng.below(120);
+                let len = rng.below(40);
+                let order = rng.below(8) as u32;
+                let size = 1 << order;
+                let test = |i: usize| model.get(i).copied().unwrap_or(false);
+
+                assert_eq!(slice.find_first_set(lb), (lb..128).find(|&i| test(i)));
+                assert_eq!(slice.find_first_unset(lb), unset_from(lb));
+                assert_eq!(Complement(slice).find_first_set(lb), unset_from(lb));
+                assert_eq!(Complement(slice).test(lb), !test(lb));
+                assert_eq!(
+                    slice.find_first_run_set(len, lb),
+                    (lb..).take(128).find(|&s| (s..s + len).all(test)),
+                );
+                assert_eq!(
+                    slice.find_first_run_unset(len, lb),
+                    (lb..).find(|&s| (s..s + len).all(|i| !test(i))),
+                );
+                assert_eq!(
+                    slice.find_aligned_free(order),
+                    (0..)
+                        .step_by(size)
+                        .find(|&s| (s..s + size).all(|i| !test(i))),
+                );
+                assert_eq!(
+                    slice.count_in_range(lb..lb + len),
+                    (lb..lb + len).filter(|&i| test(i)).count(),
+                );
+                assert_eq!(slice.find_last_set(lb), (0..=lb).rev().find(|&i| test(i)),);
+
+                #[cfg(feature = "alloc")]
+                {
+                    let vec = slice.to_vec();
+                    assert_eq!(vec.find_first_unset(lb), unset_from(lb));
+                    assert_eq!(
+                        vec.find_first_run_unset(len, lb),
+                        slice.find_first_run_unset(len, lb),
+                    );
+                    assert_eq!(vec.find_aligned_free(order), slice.find_aligned_free(order));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn id_allocator_bound_by_slice() {
+        let mut rng = Rng::new(3);
+        for _ in 0..100 {
+            let mut blocks = [0u16; 4];
+            let model = random_blocks(&mut rng, &mut blocks);
+            let max_id = blocks.len() * 16 - 1;
+            let mut ids = IdAllocator::from_bits_with_max_id(&mut blocks[..], max_id);
+
+            let free = (0..=max_id).filter(|&i| !model[i]);
+            assert!(repeat_with(|| ids.alloc().ok()).map_while(|id| id).eq(free));
+            assert!(ids.bits().test_range_all(..=max_id));
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use {
+    crate::{
+        difference::Difference, intersection::Intersection, iter::SetBits, ops::*, union::Union,
+    },
+    core::fmt::{self, Display},
+};
+
+/// Bit-set wrapper that acts like symmetric difference of two bit-sets.
+///
+/// Bit is set if it is set in exactly one of two bit-sets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SymmetricDifference<T, U>(pub T, pub U);
+
+impl<T, U> Display for SymmetricDifference<T, U>
+where
+    T: Display,
+    U: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SymmetricDifference({}, {})", self.0, self.1)
+    }
+}
+
+impl<T, U> BitEmpty for SymmetricDifference<T, U>
+where
+    T: BitEmpty,
+    U: BitEmpty,
+{
+    fn empty() -> Self {
+        SymmetricDifference(T::empty(), U::empty())
+    }
+}
+
+impl<T, U> BitFull for SymmetricDifference<T, U>
+where
+    T: BitFull,
+    U: BitEmpty,
+{
+    fn full() -> Self {
+        SymmetricDifference(T::full(), U::empty())
+    }
+}
+
+impl<T, U> BitTest for SymmetricDifference<T, U>
+where
+    T: BitTest,
+    U: BitTest,
+{
+    fn test(&self, idx: usize) -> bool {
+        self.0.test(idx) != self.1.test(idx)
+    }
+}
+
+impl<T, U> BitTestNone for SymmetricDifference<T, U>
+where
+    T: BitSubset<U>,
+    U: BitSubset<T>,
+{
+    fn test_none(&self) -> bool {
+        self.0.is_subset_of(&self.1) && self.1.is_subset_of(&self.0)
+    }
+}
+
+impl<T, U> BitTestAll for SymmetricDifference<T, U>
+where
+    SymmetricDifference<T, U>: BitSearchUnset + BitSetLimit,
+{
+    fn test_all(&self) -> bool {
+        match self.find_first_unset(0) {
+            None => true,
+            Some(idx) => idx > Self::MAX_SET_INDEX,
+        }
+    }
+}
+
+impl<T, U> BitSetLimit for SymmetricDifference<T, U>
+where
+    T: BitSetLimit,
+    U: BitSetLimit,
+{
+    const MAX_SET_INDEX: usize = crate::max(T::MAX_SET_INDEX, U::MAX_SET_INDEX);
+}
+
+impl<T, U> BitTestRange for SymmetricDifference<T, U>
+where
+    SymmetricDifference<T, U>: BitSearch + BitSearchUnset,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::range_bounds(&range, usize::MAX) {
+            None => true,
+            Some((first, last)) => match self.find_first_unset(first) {
+                None => true,
+                Some(idx) => idx > last,
+            },
+        }
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.find_set_in_range(range).is_some()
+    }
+}
+
+impl<T, U> BitSearch for SymmetricDifference<T, U>
+where
+    T: BitSearch,
+    U: BitSearch,
+{
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        let mut t = self.0.find_first_set(lower_bound);
+        let mut u = self.1.find_first_set(lower_bound);
+
+        loop {
+            match (t, u) {
+                (None, None) => return None,
+                (Some(t), None) => return Some(t),
+                (None, Some(u)) => return Some(u),
+                (Some(t_set), Some(u_set)) => {
+                    if t_set != u_set {
+                        return Some(t_set.min(u_set));
+                    } else if t_set == usize::MAX {
+                        return None;
+                    }
+
+                    // Bit is set in both bit-sets. Skip it.
+                    t = self.0.find_first_set(t_set + 1);
+                    u = self.1.find_first_set(t_set + 1);
+                }
+            }
+        }
+    }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        Some(self.0.word64(idx)? ^ self.1.word64(idx)?)
+    }
+}
+
+impl<T, U> BitSearchUnset for SymmetricDifference<T, U>
+where
+    T: BitSearch + BitSearchUnset,
+    U: BitSearch + BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        // Bit is unset when it is set in both bit-sets or unset in both.
+        let both = Intersection(&self.0, &self.1).find_first_set(lower_bound);
+        let neither = Union(&self.0, &self.1).find_first_unset(lower_bound);
+
+        match (both, neither) {
+            (None, None) => None,
+            (Some(both), None) => Some(both),
+            (None, Some(neither)) => Some(neither),
+            (Some(both), Some(neither)) => Some(both.min(neither)),
+        }
+    }
+}
+
+impl<T, U> BitSearchRun for SymmetricDifference<T, U> where
+    Self: BitSearch + BitSearchUnset + BitSetLimit
+{
+}
+
+impl<T, U> BitCount for SymmetricDifference<T, U>
+where
+    T: BitCount + BitSearch,
+    U: BitCount + BitSearch,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (start, end) = match crate::inclusive_bounds(&range, usize::MAX) {
+            None => return 0,
+            Some(bounds) => bounds,
+        };
+
+        let both = crate::count_intersection(&self.0, &self.1, start..=end);
+        let t = self.0.count_in_range(start..=end);
+        let u = self.1.count_in_range(start..=end);
+        (t - both).saturating_add(u - both)
+    }
+}
+
+impl<T, U> BitSearchRev for SymmetricDifference<T, U>
+where
+    T: BitSearchRev,
+    U: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        let mut t = self.0.find_last_set(upper_bound);
+        let mut u = self.1.find_last_set(upper_bound);
+
+        loop {
+            match (t, u) {
+                (None, None) => return None,
+                (Some(t), None) => return Some(t),
+                (None, Some(u)) => return Some(u),
+                (Some(t_set), Some(u_set)) => {
+                    if t_set != u_set {
+                        return Some(t_set.max(u_set));
+                    } else if t_set == 0 {
+                        return None;
+                    }
+
+                    // Bit is set in both bit-sets. Skip it.
+                    t = self.0.find_last_set(t_set - 1);
+                    u = self.1.find_last_set(t_set - 1);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a SymmetricDifference<T, U>
+where
+    SymmetricDifference<T, U>: BitSearch + BitSetLimit,
+{
+    type Item = usize;
+    type IntoIter = SetBits<&'a SymmetricDifference<T, U>>;
+
+    fn into_iter(self) -> SetBits<&'a SymmetricDifference<T, U>> {
+        SetBits::new(self)
+    }
+}
+
+impl<T, U> BitComplement for SymmetricDifference<T, U>
+where
+    U: BitComplement,
+{
+    type Output = SymmetricDifference<T, U::Output>;
+
+    fn complement(self) -> Self::Output {
+        SymmetricDifference(self.0, self.1.complement())
+    }
+}
+
+impl<T, U, Y> BitUnion<Y> for SymmetricDifference<T, U> {
+    type Output = Union<SymmetricDifference<T, U>, Y>;
+
+    fn union(self, rhs: Y) -> Self::Output {
+        Union(self, rhs)
+    }
+}
+
+impl<T, U, Y> BitIntersection<Y> for SymmetricDifference<T, U> {
+    type Output = Intersection<SymmetricDifference<T, U>, Y>;
+
+    fn intersection(self, rhs: Y) -> Self::Output {
+        Intersection(self, rhs)
+    }
+}
+
+impl<T, U, Y> BitDifference<Y> for SymmetricDifference<T, U> {
+    type Output = Difference<SymmetricDifference<T, U>, Y>;
+
+    fn difference(self, rhs: Y) -> Self::Output {
+        Difference(self, rhs)
+    }
+}
+
+impl<T, U, Y> BitSymmetricDifference<Y> for SymmetricDifference<T, U> {
+    type Output = SymmetricDifference<SymmetricDifference<T, U>, Y>;
+
+    fn symmetric_difference(self, rhs: Y) -> Self::Output {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl_bit_operators!(impl[T, U] for SymmetricDifference<T, U>);
+impl_bit_operators!(impl['a, T, U] for &'a SymmetricDifference<T, U>);
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            test_util::{from_model, Rng},
+            Bits4096,
+        },
+    };
+
+    #[test]
+    fn matches_model() {
+        let mut rng = Rng::new(11);
+        for _ in 0..20 {
+            let model = rng.runs(4096, 100);
+            let other = rng.overlapping(&model, 64);
+            let expected: Vec<bool> = model.iter().zip(&other).map(|(a, b)| a != b).collect();
+            let ones: Vec<usize> = (0..4096).filter(|&idx| expected[idx]).collect();
+
+            let a: Bits4096 = from_model(&model);
+            let b: Bits4096 = from_model(&other);
+            let xor = SymmetricDifference(a, b);
+            assert!(xor.iter_set().eq(ones.iter().copied()));
+            assert!(xor.iter_set().rev().eq(ones.iter().rev().copied()));
+            assert_eq!(xor.test_none(), ones.is_empty());
+
+            for _ in 0..50 {
+                let idx = rng.below(4100);
+                let end = idx + rng.below(300);
+                let first_unset = (idx..).find(|&i| i >= 4096 || !expected[i]);
+                assert_eq!(xor.test(idx), idx < 4096 && expected[idx]);
+                assert_eq!(xor.find_first_unset(idx), first_unset);
+                assert_eq!(
+                    xor.count_in_range(idx..end),
+                    ones.iter().filter(|&&i| (idx..end).contains(&i)).count()
+                );
+            }
+        }
+    }
+}
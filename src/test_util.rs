@@ -0,0 +1,142 @@
+//! Helpers for tests that compare bit-sets with reference models.
+
+use crate::ops::*;
+
+/// Deterministic xorshift generator, so failures are reproducible.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed | 1)
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Returns model of `n` bits made of alternating runs up to `max_run` bits long.
+    pub fn runs(&mut self, n: usize, max_run: usize) -> Vec<bool> {
+        let mut model = Vec::with_capacity(n);
+        let mut value = self.below(2) == 0;
+        while model.len() < n {
+            let len = (1 + self.below(max_run)).min(n - model.len());
+            model.resize(model.len() + len, value);
+            value = !value;
+        }
+        model
+    }
+
+    /// Returns copy of `model` where each block of `block` bits is either kept,
+    /// cleared or replaced with random runs, so bit-wise operations cancel whole blocks.
+    pub fn overlapping(&mut self, model: &[bool], block: usize) -> Vec<bool> {
+        let mut other = model.to_vec();
+        for chunk in other.chunks_mut(block) {
+            match self.below(3) {
+                0 => {}
+                1 => chunk.fill(false),
+                _ => {
+                    let len = chunk.len();
+                    chunk.copy_from_slice(&self.runs(len, len / 4 + 1));
+                }
+            }
+        }
+        other
+    }
+}
+
+/// Returns bit-set with bits set where model is `true`.
+pub fn from_model<S: BitEmpty + BitSet>(model: &[bool]) -> S {
+    let mut set = S::empty();
+    for (idx, _) in model.iter().enumerate().filter(|(_, &x)| x) {
+        set.set(idx);
+    }
+    set
+}
+
+/// Returns start of first run of `len` bits equal to `value` starting with `lower_bound`.
+fn find_run(model: &[bool], value: bool, len: usize, lower_bound: usize) -> Option<usize> {
+    if len == 0 {
+        return Some(lower_bound).filter(|&idx| idx < model.len());
+    }
+
+    let mut run = 0;
+    for (idx, &x) in model.iter().enumerate().skip(lower_bound) {
+        run = if x == value { run + 1 } else { 0 };
+        if run == len {
+            return Some(idx + 1 - len);
+        }
+    }
+    None
+}
+
+/// Compares run searches of bit-sets built from random models with brute force answers.
+/// Runs up to `max_run` bits long are generated, so they span several words or bottom layers.
+pub fn check_runs<S>(rng: &mut Rng, max_run: usize)
+where
+    S: BitEmpty + BitSet + BitSearchRun,
+{
+    let n = S::MAX_SET_INDEX + 1;
+    for _ in 0..20 {
+        let model = rng.runs(n, max_run);
+        let set: S = from_model(&model);
+
+        for _ in 0..50 {
+            let len = rng.below(2 * max_run);
+            let lower_bound = rng.below(n + 8);
+            assert_eq!(
+                set.find_first_run_set(len, lower_bound),
+                find_run(&model, true, len, lower_bound),
+                "set run of {} from {}",
+                len,
+                lower_bound
+            );
+            assert_eq!(
+                set.find_first_run_unset(len, lower_bound),
+                find_run(&model, false, len, lower_bound),
+                "unset run of {} from {}",
+                len,
+                lower_bound
+            );
+        }
+
+        for order in 0..=n.trailing_zeros() + 1 {
+            let size = 1 << order;
+            let expected = model
+                .chunks(size)
+                .position(|block| block.len() == size && block.iter().all(|&x| !x))
+                .map(|block| block * size);
+            assert_eq!(set.find_aligned_free(order), expected, "order {}", order);
+        }
+    }
+}
+
+/// Compares rank and select of bit-sets built from random models with brute force answers.
+pub fn check_rank_select<S>(rng: &mut Rng, max_run: usize)
+where
+    S: BitEmpty + BitSet + BitRankSelect,
+{
+    let n = S::MAX_SET_INDEX + 1;
+    for _ in 0..20 {
+        let model = rng.runs(n, max_run);
+        let set: S = from_model(&model);
+        let ones: Vec<usize> = (0..n).filter(|&idx| model[idx]).collect();
+        assert_eq!(set.count_ones(), ones.len());
+
+        for _ in 0..50 {
+            let idx = rng.below(n + 8);
+            let rank = ones.partition_point(|&i| i < idx);
+            assert_eq!(set.rank(idx), rank, "rank of {}", idx);
+
+            let k = rng.below(ones.len() + 2);
+            assert_eq!(set.select(k), ones.get(k).copied(), "select of {}", k);
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use {
-    crate::{intersection::Intersection, ops::*},
+    crate::{
+        intersection::Intersection, iter::SetBits, ops::*,
+        symmetric_difference::SymmetricDifference,
+    },
     core::fmt::{self, Display},
 };
 
@@ -117,6 +120,31 @@ where
     }
 }
 
+impl<T, U> BitTestRange for Union<T, U>
+where
+    Union<T, U>: BitSearch + BitSearchUnset,
+{
+    fn test_range_all<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        match crate::range_bounds(&range, usize::MAX) {
+            None => true,
+            Some((first, last)) => match self.find_first_unset(first) {
+                None => true,
+                Some(idx) => idx > last,
+            },
+        }
+    }
+
+    fn test_range_any<R>(&self, range: R) -> bool
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        self.find_set_in_range(range).is_some()
+    }
+}
+
 impl<T, U> BitSearch for Union<T, U>
 where
     T: BitSearch,
@@ -133,6 +161,84 @@ where
             (Some(t), Some(u)) => Some(t.min(u)),
         }
     }
+
+    fn word64(&self, idx: usize) -> Option<u64> {
+        Some(self.0.word64(idx)? | self.1.word64(idx)?)
+    }
+}
+
+impl<T, U> BitSearchUnset for Union<T, U>
+where
+    T: BitSearchUnset,
+    U: BitSearchUnset,
+{
+    fn find_first_unset(&self, lower_bound: usize) -> Option<usize> {
+        let mut t = self.0.find_first_unset(lower_bound)?;
+        let mut u = self.1.find_first_unset(lower_bound)?;
+
+        loop {
+            if t == u {
+                return Some(t);
+            } else if t < u {
+                t = self.0.find_first_unset(u)?;
+            } else {
+                u = self.1.find_first_unset(t)?;
+            }
+        }
+    }
+}
+
+impl<T, U> BitSearchRun for Union<T, U> where Self: BitSearch + BitSearchUnset + BitSetLimit {}
+
+impl<T, U> BitCount for Union<T, U>
+where
+    T: BitCount + BitSearch,
+    U: BitCount + BitSearch,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        let (start, end) = match crate::inclusive_bounds(&range, usize::MAX) {
+            None => return 0,
+            Some(bounds) => bounds,
+        };
+
+        let both = crate::count_intersection(&self.0, &self.1, start..=end);
+        let t = self.0.count_in_range(start..=end);
+        let u = self.1.count_in_range(start..=end);
+        (t - both).saturating_add(u)
+    }
+}
+
+impl<T, U> BitSearchRev for Union<T, U>
+where
+    T: BitSearchRev,
+    U: BitSearchRev,
+{
+    fn find_last_set(&self, upper_bound: usize) -> Option<usize> {
+        let t = self.0.find_last_set(upper_bound);
+        let u = self.1.find_last_set(upper_bound);
+
+        match (t, u) {
+            (None, None) => None,
+            (Some(t), None) => Some(t),
+            (None, Some(u)) => Some(u),
+            (Some(t), Some(u)) => Some(t.max(u)),
+        }
+    }
+}
+
+impl<'a, T, U> IntoIterator for &'a Union<T, U>
+where
+    Union<T, U>: BitSearch + BitSetLimit,
+{
+    type Item = usize;
+    type IntoIter = SetBits<&'a Union<T, U>>;
+
+    fn into_iter(self) -> SetBits<&'a Union<T, U>> {
+        SetBits::new(self)
+    }
 }
 
 impl<T, U> BitComplement for Union<T, U>
@@ -183,3 +289,14 @@ where
         Union(self.0.difference(rhs), self.1.difference(rhs))
     }
 }
+
+impl<T, U, Y> BitSymmetricDifference<Y> for Union<T, U> {
+    type Output = SymmetricDifference<Union<T, U>, Y>;
+
+    fn symmetric_difference(self, rhs: Y) -> Self::Output {
+        SymmetricDifference(self, rhs)
+    }
+}
+
+impl_bit_operators!(impl[T, U] for Union<T, U>);
+impl_bit_operators!(impl['a, T, U] for &'a Union<T, U>);
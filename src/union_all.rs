@@ -0,0 +1,245 @@
+use {crate::ops::*, core::ops::Deref};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::{collections::BinaryHeap, vec::Vec},
+    core::{cmp::Reverse, iter::FusedIterator},
+};
+
+/// Bit-set wrapper that acts like union of any number of bit-sets.
+///
+/// Wraps slice-like collection of bit-sets, e.g. `&[S]` or `Vec<S>`.
+/// Union of empty collection has no bits set.
+///
+/// `find_first_set` searches every bit-set on each call, which is intended for one-off searches.
+/// `UnionAll::iter` keeps a min-heap of cursors to iterate without searching every bit-set again.
+///
+/// # Example
+///
+/// ```
+/// # use bitsetium::*;
+/// let sets = [0b0001u8, 0b0100, 0b1000_0000];
+/// let union = UnionAll(&sets[..]);
+///
+/// assert!(union.test(2) && !union.test(1));
+/// assert!(union.iter_set().eq([0, 2, 7]));
+/// assert_eq!(union.fold::<u8>(), 0b1000_0101);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnionAll<C>(pub C);
+
+impl<C, S> UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+{
+    /// Returns bit-sets in the union.
+    pub fn sets(&self) -> &[S] {
+        &self.0
+    }
+
+    /// Evaluates union into concrete bit-set, merging whole bit-sets at once.
+    pub fn fold<T>(&self) -> T
+    where
+        T: BitEmpty + BitUnionAssign<S>,
+    {
+        let mut result = T::empty();
+        for set in self.sets() {
+            result.union_assign(set);
+        }
+        result
+    }
+}
+
+impl<C, S> BitTest for UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitTest,
+{
+    fn test(&self, idx: usize) -> bool {
+        self.sets().iter().any(|set| set.test(idx))
+    }
+}
+
+impl<C, S> BitTestNone for UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitTestNone,
+{
+    fn test_none(&self) -> bool {
+        self.sets().iter().all(|set| set.test_none())
+    }
+}
+
+impl<C, S> BitSetLimit for UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSetLimit,
+{
+    const MAX_SET_INDEX: usize = S::MAX_SET_INDEX;
+}
+
+impl<C, S> BitSearch for UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch,
+{
+    fn find_first_set(&self, lower_bound: usize) -> Option<usize> {
+        self.sets()
+            .iter()
+            .filter_map(|set| set.find_first_set(lower_bound))
+            .min()
+    }
+}
+
+impl<C, S> BitCount for UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch,
+{
+    fn count_in_range<R>(&self, range: R) -> usize
+    where
+        R: core::ops::RangeBounds<usize>,
+    {
+        crate::count_by_search(self, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C, S> UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch,
+{
+    /// Returns iterator over indices of bits set.
+    ///
+    /// Unlike iterating with `BitIter::iter_set` this iterator keeps a cursor
+    /// into each bit-set in a min-heap and advances only those
+    /// that are at the yielded index.
+    pub fn iter(&self) -> UnionAllIter<'_, S> {
+        let heap = self
+            .sets()
+            .iter()
+            .enumerate()
+            .filter_map(|(member, set)| Some(Reverse((set.find_first_set(0)?, member))))
+            .collect();
+
+        UnionAllIter {
+            sets: self.sets(),
+            heap,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, C, S> IntoIterator for &'a UnionAll<C>
+where
+    C: Deref<Target = [S]>,
+    S: BitSearch + 'a,
+{
+    type Item = usize;
+    type IntoIter = UnionAllIter<'a, S>;
+
+    fn into_iter(self) -> UnionAllIter<'a, S> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> core::iter::FromIterator<S> for UnionAll<Vec<S>> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        UnionAll(iter.into_iter().collect())
+    }
+}
+
+/// Iterator over indices of bits set in `UnionAll`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct UnionAllIter<'a, S> {
+    sets: &'a [S],
+    /// Next bit set in each bit-set that is not exhausted yet.
+    heap: BinaryHeap<Reverse<(usize, usize)>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<S> UnionAllIter<'_, S>
+where
+    S: BitSearch,
+{
+    fn advance(&mut self, idx: usize, member: usize) {
+        if idx < usize::MAX {
+            if let Some(next) = self.sets[member].find_first_set(idx + 1) {
+                self.heap.push(Reverse((next, member)));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> Iterator for UnionAllIter<'_, S>
+where
+    S: BitSearch,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let Reverse((idx, member)) = self.heap.pop()?;
+        self.advance(idx, member);
+
+        // Same bit may be set in other bit-sets.
+        while let Some(&Reverse((next, member))) = self.heap.peek() {
+            if next != idx {
+                break;
+            }
+            self.heap.pop();
+            self.advance(idx, member);
+        }
+
+        Some(idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> FusedIterator for UnionAllIter<'_, S> where S: BitSearch {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{test_util::Rng, Bits4096},
+    };
+
+    #[test]
+    fn matches_model() {
+        let mut rng = Rng::new(13);
+        for _ in 0..30 {
+            let mut sets = [Bits4096::empty(); 12];
+            let mut model = [false; 4096];
+            let members = rng.below(sets.len() + 1);
+            for set in &mut sets[..members] {
+                for _ in 0..rng.below(40) {
+                    let idx = rng.below(4096);
+                    set.set(idx);
+                    model[idx] = true;
+                }
+            }
+
+            let union = UnionAll(&sets[..members]);
+            let expected = (0..4096).filter(|&i| model[i]);
+            assert!(union.iter_set().eq(expected.clone()));
+            #[cfg(feature = "alloc")]
+            assert!(union.iter().eq(expected.clone()));
+            assert_eq!(union.count_ones(), expected.clone().count());
+            assert_eq!(union.test_none(), expected.clone().next().is_none());
+            assert!(union.fold::<Bits4096>().iter_set().eq(expected.clone()));
+
+            for _ in 0..20 {
+                let lower_bound = rng.below(4100);
+                let expected = (lower_bound..4096).find(|&i| model[i]);
+                assert_eq!(union.find_first_set(lower_bound), expected);
+            }
+        }
+    }
+}